@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use random_distributions::prelude::{Binomial, SampleDistribution, Uniform};
+use random_numbers::{RandomNumber, RandomNumberAlgorithm, RandomNumberInit, Xoshiro256SS};
+use randomness::prelude::*;
+
+// enough draws per iteration that inlining the loop body (or not) shows up
+const DRAWS: usize = 1000;
+
+fn sum_u64s<R: RandomNumber>(rand: &mut R) -> u64 {
+    (0..DRAWS).fold(0, |sum, _| sum.wrapping_add(rand.next_u64()))
+}
+
+fn sum_samples<D: SampleDistribution<f64>>(distribution: &mut D) -> f64 {
+    (0..DRAWS).fold(0.0, |sum, _| sum + distribution.sample())
+}
+
+fn count_successes<D: SampleDistribution<bool>>(distribution: &mut D) -> u32 {
+    (0..DRAWS).filter(|_| distribution.sample()).count() as u32
+}
+
+fn bench_init(c: &mut Criterion) {
+    c.bench_function("boxed_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256SS, black_box(0))));
+    c.bench_function("static_init_with_seed", |b| b.iter(|| Xoshiro256SS::from_seed(black_box(0))));
+}
+
+fn bench_random_number(c: &mut Criterion) {
+    let mut boxed = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256SS, black_box(0));
+    let mut concrete = Xoshiro256SS::from_seed(black_box(0));
+
+    c.bench_function("boxed_random_number_1000_u64s", |b| b.iter(|| sum_u64s(&mut boxed)));
+    c.bench_function("static_random_number_1000_u64s", |b| b.iter(|| sum_u64s(&mut concrete)));
+}
+
+fn bench_uniform(c: &mut Criterion) {
+    let mut boxed = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, black_box(0));
+    let mut concrete = Uniform::from_random_number(Xoshiro256SS::from_seed(black_box(0)));
+
+    c.bench_function("boxed_uniform_1000_f64s", |b| b.iter(|| sum_samples(&mut boxed)));
+    c.bench_function("static_uniform_1000_f64s", |b| b.iter(|| sum_samples(&mut concrete)));
+}
+
+fn bench_binomial(c: &mut Criterion) {
+    let mut boxed = Binomial::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0.3, black_box(0));
+    let mut concrete = Binomial::from_random_number(Xoshiro256SS::from_seed(black_box(0)), 0.3);
+
+    c.bench_function("boxed_binomial_1000_bools", |b| b.iter(|| count_successes(&mut boxed)));
+    c.bench_function("static_binomial_1000_bools", |b| b.iter(|| count_successes(&mut concrete)));
+}
+
+fn bench_random(c: &mut Criterion) {
+    let mut boxed = new_random_from_seed(black_box(0));
+    let mut concrete = Random::from_random_number(Xoshiro256SS::from_seed(black_box(0)));
+
+    c.bench_function("boxed_random_1000_u64s", |b| b.iter(|| (0..DRAWS).fold(0u64, |sum, _| sum.wrapping_add(boxed.next_u64()))));
+    c.bench_function("static_random_1000_u64s", |b| b.iter(|| (0..DRAWS).fold(0u64, |sum, _| sum.wrapping_add(concrete.next_u64()))));
+}
+
+criterion_group!(init_dispatch_benches, bench_init);
+criterion_group!(generate_dispatch_benches, bench_random_number, bench_uniform, bench_binomial, bench_random);
+
+criterion_main!(init_dispatch_benches, generate_dispatch_benches);
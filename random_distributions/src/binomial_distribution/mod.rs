@@ -6,12 +6,17 @@ use std::fmt::Debug;
 
 use crate::prelude::*;
 
+/// The binomial distribution, sampling from a generator of type `R`.  See [`Uniform`] for when to use a
+/// concrete generator rather than the boxed default ([`BoxedBinomial`]).
 #[derive(Debug)]
-pub struct Binomial {
-    random_number: Box<dyn RandomNumber>,
+pub struct Binomial<R: RandomNumber = Box<dyn RandomNumber>> {
+    random_number: R,
     success_probability: f64
 }
 
+/// A [`Binomial`] over a generator picked at runtime with [`RandomNumberAlgorithm`].
+pub type BoxedBinomial = Binomial<Box<dyn RandomNumber>>;
+
 #[derive(Debug)]
 pub struct BinomialParams {
     num_trials: u64
@@ -25,15 +30,15 @@ pub enum BinomialError {
     NumTrialsTooSmall,
 }
 
-impl Binomial {
-     pub fn new(random_algorithm: RandomNumberAlgorithm, success_probability: f64) -> Binomial {
+impl BoxedBinomial {
+     pub fn new(random_algorithm: RandomNumberAlgorithm, success_probability: f64) -> BoxedBinomial {
         Binomial {
             random_number: random_numbers::new(random_algorithm),
             success_probability: success_probability
         }
     }
 
-    pub fn from_seed(random_algorithm: RandomNumberAlgorithm, success_probability: f64, seed: u64) -> Binomial {
+    pub fn from_seed(random_algorithm: RandomNumberAlgorithm, success_probability: f64, seed: u64) -> BoxedBinomial {
         Binomial {
             random_number: random_numbers::from_seed(random_algorithm, seed),
             success_probability: success_probability
@@ -41,14 +46,26 @@ impl Binomial {
     }
 }
 
-impl SampleDistribution<bool> for Binomial {
+impl<R: RandomNumber> Binomial<R> {
+    /// Samples from a random number generator you've already created, e.g. a concrete generator like
+    /// `Xoshiro256SS::from_seed(0)`, one restored from a saved state, or a `rand` generator wrapped in
+    /// `random_numbers::RngCoreAdapter`.
+    pub fn from_random_number(random_number: R, success_probability: f64) -> Binomial<R> {
+        Binomial {
+            random_number,
+            success_probability
+        }
+    }
+}
+
+impl<R: RandomNumber> SampleDistribution<bool> for Binomial<R> {
     #[inline]
     fn sample(&mut self) -> bool {
         self.random_number.next_f64() < self.success_probability
     }
 }
 
-impl DiscreteDistribution<BinomialParams> for Binomial {
+impl<R: RandomNumber> DiscreteDistribution<BinomialParams> for Binomial<R> {
     fn pdf(&mut self, k: u64, params: &BinomialParams) -> f64 {
         let k_f64 = k as f64;
 
@@ -68,3 +85,21 @@ fn ln_n_choose_k(n: u64, k: u64) -> f64 {
 fn ln_factorial(n: u64) -> f64 {
     (n..0).fold(0f64, |sum, next| (next as f64).ln() + sum)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concrete_random_number_test() {
+        let mut binomial = Binomial::from_random_number(Xoshiro256SS::from_seed(0), 0.5);
+        let mut expected = Binomial::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0.5, 0);
+
+        for _ in 0..100 {
+            let b: bool = binomial.sample();
+            let e: bool = expected.sample();
+
+            assert_eq!(b, e);
+        }
+    }
+}
@@ -8,11 +8,13 @@
 pub mod prelude {
     pub use crate::*;
     pub use crate::SampleDistribution;
-    pub use crate::uniform_distribution::Uniform;
+    pub use crate::binomial_distribution::{Binomial, BoxedBinomial};
+    pub use crate::uniform_distribution::{BoxedUniform, Uniform};
 }
 
 mod binomial_distribution;
 // TODO add a normal distribution
+mod sample_iter;
 mod uniform_distribution;
 
 use std::ops::{Add, Sub, Mul};
@@ -22,6 +24,8 @@ use random_numbers::prelude::*;
 use binomial_distribution::*;
 use uniform_distribution::*;
 
+pub use sample_iter::SampleIter;
+
 pub trait DistributionParams {}
 
 pub trait DiscreteDistribution<T: DistributionParams> {
@@ -46,8 +50,33 @@ pub trait ContinuousDistribution<T: DistributionParams> {
 /// Calling `sample` will produce a new instance of `T`.
 pub trait SampleDistribution<T: ?Sized> {
     fn sample(&mut self) -> T;
+
+    /// Returns an infinite iterator of samples, e.g. `uniform.sample_iter().take(10)`.  When the
+    /// distribution can sample several types, annotate the one you want:
+    /// 
+    /// ```
+    /// use random_distributions::prelude::*;
+    /// 
+    /// let mut uniform = new_uniform(None);
+    /// 
+    /// let samples: Vec<f64> = uniform.sample_iter().take(10).collect();
+    /// ```
+    #[inline]
+    fn sample_iter(&mut self) -> SampleIter<'_, Self, T> where Self: Sized, T: Sized {
+        SampleIter::new(self)
+    }
+
+    /// Returns `n` samples.
+    fn sample_n(&mut self, n: usize) -> Vec<T> where Self: Sized, T: Sized {
+        self.sample_iter().take(n).collect()
+    }
 }
 
+/// Defines a random distribution over an interval of a type `T`.
+/// 
+/// Calling `sample_interval` will produce a new instance of `T` in `[min, max]`, so both ends are
+/// included and the type's whole range (e.g. `i64::MIN..=i64::MAX`) can be sampled.  Panics if
+/// `min > max`.
 pub trait SampleIntervalDistribution<T: Ord + Add + Sub + Mul> {
     fn sample_interval(&mut self, min: T, max: T) -> T;
 }
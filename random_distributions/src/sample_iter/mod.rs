@@ -0,0 +1,46 @@
+// Copyright (c) 2020 Mark Junod. Subject to the MIT License.
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::SampleDistribution;
+
+/// An infinite iterator of samples, borrowing its distribution.  Returned by
+/// [`SampleDistribution::sample_iter`]; use [`Iterator::take`] to get a finite number of samples.
+pub struct SampleIter<'a, D, T> {
+    distribution: &'a mut D,
+    marker: PhantomData<fn() -> T>
+}
+
+impl<'a, D: SampleDistribution<T>, T> SampleIter<'a, D, T> {
+    pub(crate) fn new(distribution: &'a mut D) -> SampleIter<'a, D, T> {
+        SampleIter {
+            distribution,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<'a, D: SampleDistribution<T>, T> Iterator for SampleIter<'a, D, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some(self.distribution.sample())
+    }
+
+    // never ends, like std::iter::repeat, so `take(n)` reports exactly n
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<'a, D: SampleDistribution<T>, T> FusedIterator for SampleIter<'a, D, T> {}
+
+impl<'a, D: fmt::Debug, T> fmt::Debug for SampleIter<'a, D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SampleIter").field("distribution", &self.distribution).finish()
+    }
+}
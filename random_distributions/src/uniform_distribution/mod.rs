@@ -4,11 +4,17 @@
 
 use crate::prelude::*;
 
+/// The uniform distribution, sampling from a generator of type `R`.  Use a concrete generator (e.g.
+/// `Uniform<Xoshiro256SS>`) in hot loops, so every call can be inlined without a heap allocation, or the
+/// boxed default ([`BoxedUniform`]) to pick the algorithm at runtime.
 #[derive(Debug)]
-pub struct Uniform {
-    random_number: Box<dyn RandomNumber>
+pub struct Uniform<R: RandomNumber = Box<dyn RandomNumber>> {
+    random_number: R
 }
 
+/// A [`Uniform`] over a generator picked at runtime with [`RandomNumberAlgorithm`].
+pub type BoxedUniform = Uniform<Box<dyn RandomNumber>>;
+
 #[derive(Debug)]
 pub struct DiscreteUniformParams {
     num_classes: u64
@@ -24,23 +30,37 @@ pub struct ContinuousUniformParams {
 
 impl DistributionParams for ContinuousUniformParams {}
 
-impl Uniform {
-     pub fn new(random_algorithm: RandomNumberAlgorithm) -> Uniform {
+impl BoxedUniform {
+     pub fn new(random_algorithm: RandomNumberAlgorithm) -> BoxedUniform {
         Uniform {
             random_number: random_numbers::new(random_algorithm)
         }
     }
 
-    pub fn from_seed(random_algorithm: RandomNumberAlgorithm, seed: u64) -> Uniform {
+    pub fn from_seed(random_algorithm: RandomNumberAlgorithm, seed: u64) -> BoxedUniform {
         Uniform {
             random_number: random_numbers::from_seed(random_algorithm, seed)
         }
     }
 }
 
+impl<R: RandomNumber> Uniform<R> {
+    /// Samples from a random number generator you've already created, e.g. a concrete generator like
+    /// `Xoshiro256SS::from_seed(0)`, one restored from a saved state, or a `rand` generator wrapped in
+    /// `random_numbers::RngCoreAdapter`.
+    pub fn from_random_number(random_number: R) -> Uniform<R> {
+        Uniform { random_number }
+    }
+
+    /// Returns the wrapped generator.
+    pub fn into_inner(self) -> R {
+        self.random_number
+    }
+}
+
 macro_rules! make_sample_dist {
     ($next_name: ident, $sample_type: ty) => {
-        impl SampleDistribution<$sample_type> for Uniform {
+        impl<R: RandomNumber> SampleDistribution<$sample_type> for Uniform<R> {
             #[inline]
             fn sample(&mut self) -> $sample_type {
                 self.random_number.$next_name()
@@ -63,27 +83,69 @@ make_sample_dist!(next_i128, i128);
 make_sample_dist!(next_f32, f32);
 make_sample_dist!(next_f64, f64);
 
-impl Uniform {
-    pub fn sample_f32_range(&mut self, min: f32, max: f32) -> f32 {
-        let f: f32 = self.sample();
+// macro to implement sampling integers from an interval, without bias
+macro_rules! make_sample_interval_dist {
+    ($range_name: ident, $sample_type: ty) => {
+        impl<R: RandomNumber> SampleIntervalDistribution<$sample_type> for Uniform<R> {
+            #[inline]
+            fn sample_interval(&mut self, min: $sample_type, max: $sample_type) -> $sample_type {
+                self.random_number.$range_name(min, max)
+            }
+        }
+    };
+}
 
-        f * (max - min) + min
-    }
+make_sample_interval_dist!(next_u8_range_inclusive, u8);
+make_sample_interval_dist!(next_i8_range_inclusive, i8);
+make_sample_interval_dist!(next_u16_range_inclusive, u16);
+make_sample_interval_dist!(next_i16_range_inclusive, i16);
+make_sample_interval_dist!(next_u32_range_inclusive, u32);
+make_sample_interval_dist!(next_i32_range_inclusive, i32);
+make_sample_interval_dist!(next_u64_range_inclusive, u64);
+make_sample_interval_dist!(next_i64_range_inclusive, i64);
+make_sample_interval_dist!(next_u128_range_inclusive, u128);
+make_sample_interval_dist!(next_i128_range_inclusive, i128);
+make_sample_interval_dist!(next_usize_range_inclusive, usize);
+make_sample_interval_dist!(next_isize_range_inclusive, isize);
 
-    pub fn sample_f64_range(&mut self, min: f64, max: f64) -> f64 {
-        let f: f64 = self.sample();
+macro_rules! make_sample_float_range {
+    ($range_name: ident, $sample_type: ty) => {
+        /// Returns a random value in `[min, max)`.  Rounding can push `f * (max - min) + min` up to `max`
+        /// when `f` is close to 1, so those values are rejected and redrawn.
+        ///
+        /// # Panics
+        ///
+        /// If `min >= max`, or `max - min` isn't finite.
+        pub fn $range_name(&mut self, min: $sample_type, max: $sample_type) -> $sample_type {
+            assert!(min < max, "the range must not be empty");
 
-        f * (max - min) + min
-    }
+            let scale = max - min;
+            assert!(scale.is_finite(), "the range must be finite");
+
+            loop {
+                let f: $sample_type = self.sample();
+                let x = f * scale + min;
+
+                if x < max {
+                    return x;
+                }
+            }
+        }
+    };
+}
+
+impl<R: RandomNumber> Uniform<R> {
+    make_sample_float_range!(sample_f32_range, f32);
+    make_sample_float_range!(sample_f64_range, f64);
 }
 
-impl DiscreteDistribution<DiscreteUniformParams> for Uniform {
+impl<R: RandomNumber> DiscreteDistribution<DiscreteUniformParams> for Uniform<R> {
     fn pdf(&mut self, _k: u64, params: &DiscreteUniformParams) -> f64 {
         1.0 / (params.num_classes as f64)
     }
 }
 
-impl ContinuousDistribution<ContinuousUniformParams> for Uniform {
+impl<R: RandomNumber> ContinuousDistribution<ContinuousUniformParams> for Uniform<R> {
     // the measure of a single point in any interval is always 0
     fn pdf(&mut self, _x: f64, _params: &ContinuousUniformParams) -> f64 {
         0.0
@@ -107,4 +169,160 @@ mod tests {
 
         assert!(b);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn from_random_number_test() {
+        let mut uniform = Uniform::from_random_number(random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0));
+        let mut expected = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        let n: u64 = uniform.sample();
+        let e: u64 = expected.sample();
+
+        assert_eq!(n, e);
+    }
+
+    #[test]
+    fn concrete_random_number_test() {
+        let mut uniform = Uniform::from_random_number(Xoshiro256SS::from_seed(0));
+        let mut expected = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        let n: u64 = uniform.sample();
+        let e: u64 = expected.sample();
+        assert_eq!(n, e);
+
+        let f = uniform.sample_f64_range(1.0, 2.0);
+        assert_eq!(f, expected.sample_f64_range(1.0, 2.0));
+
+        let mut rand = uniform.into_inner();
+        let mut expected = expected.into_inner();
+        assert_eq!(rand.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn borrowed_random_number_test() {
+        let mut rand = Xoshiro256SS::from_seed(0);
+
+        let n: u32 = Uniform::from_random_number(&mut rand).sample_interval(0, 9);
+        assert!(n <= 9);
+
+        // the borrowed generator was advanced
+        assert_ne!(rand.next_u64(), Xoshiro256SS::from_seed(0).next_u64());
+    }
+
+    #[test]
+    fn sample_interval_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        for _ in 0..1000 {
+            let n: u8 = uniform.sample_interval(250, u8::MAX);
+            assert!(n >= 250);
+
+            let n: i32 = uniform.sample_interval(-3, 3);
+            assert!((-3..=3).contains(&n));
+
+            let n: u128 = uniform.sample_interval(5, 5);
+            assert_eq!(n, 5);
+
+            let n: usize = uniform.sample_interval(0, 9);
+            assert!(n <= 9);
+        }
+    }
+
+    #[test]
+    fn sample_interval_includes_both_ends_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+        let mut seen = [false; 3];
+
+        for _ in 0..100 {
+            let n: i16 = uniform.sample_interval(i16::MAX - 2, i16::MAX);
+            seen[(n - (i16::MAX - 2)) as usize] = true;
+        }
+
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn sample_interval_full_range_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+        let mut negatives = 0;
+
+        for _ in 0..10_000 {
+            let n: i64 = uniform.sample_interval(i64::MIN, i64::MAX);
+
+            if n < 0 {
+                negatives += 1;
+            }
+        }
+
+        assert!(negatives > 4_500 && negatives < 5_500);
+
+        let _: i128 = uniform.sample_interval(i128::MIN, i128::MAX);
+        let _: u32 = uniform.sample_interval(0, u32::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sample_interval_empty_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        let _: u64 = uniform.sample_interval(2, 1);
+    }
+
+    #[test]
+    fn sample_float_range_never_max_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        // f * (max - min) + min rounds up to max for about half of these, so only min is possible
+        for _ in 0..1000 {
+            assert_eq!(uniform.sample_f64_range(1.0, 1.0 + f64::EPSILON), 1.0);
+            assert_eq!(uniform.sample_f32_range(1.0, 1.0 + f32::EPSILON), 1.0);
+        }
+
+        for _ in 0..1000 {
+            let x = uniform.sample_f64_range(-2.5, 7.0);
+            assert!((-2.5..7.0).contains(&x));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn sample_float_range_empty_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        uniform.sample_f64_range(1.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sample_float_range_infinite_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+
+        uniform.sample_f64_range(f64::MIN, f64::MAX);
+    }
+
+    #[test]
+    fn sample_iter_test() {
+        let mut uniform = Uniform::from_seed(RandomNumberAlgorithm::Xoshiro256SS, 0);
+        let mut expected = Xoshiro256SS::from_seed(0);
+
+        let samples: Vec<u64> = uniform.sample_iter().take(5).collect();
+
+        for n in samples {
+            assert_eq!(n, expected.next_u64());
+        }
+
+        let iter = SampleDistribution::<f64>::sample_iter(&mut uniform).take(7);
+        assert_eq!(iter.size_hint(), (7, Some(7)));
+    }
+
+    #[test]
+    fn sample_n_test() {
+        let mut uniform = Uniform::from_random_number(Xoshiro256SS::from_seed(0));
+        let mut expected = Xoshiro256SS::from_seed(0);
+
+        let samples: Vec<f64> = uniform.sample_n(3);
+
+        assert_eq!(samples, vec![expected.next_f64(), expected.next_f64(), expected.next_f64()]);
+        assert!(SampleDistribution::<bool>::sample_n(&mut uniform, 0).is_empty());
+    }
+}
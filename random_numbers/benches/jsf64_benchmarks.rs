@@ -0,0 +1,54 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use random_numbers::prelude::*;
+
+// the fill benchmarks report their throughput, in GiB/s
+const BUFFER_BYTES: usize = 64 * 1024;
+
+fn bench_jsf64_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("jsf64_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Jsf64)));
+}
+
+fn bench_jsf64_init_with_seed(c: &mut Criterion) {
+    c.bench_function("jsf64_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Jsf64, black_box(0))));
+}
+
+fn bench_jsf64_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Jsf64, black_box(0));
+    
+    c.bench_function("jsf64_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("jsf64_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("jsf64_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("jsf64_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("jsf64_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("jsf64_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("jsf64_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("jsf64_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("jsf64_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("jsf64_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("jsf64_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("jsf64_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("jsf64_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_jsf64_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Jsf64, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("jsf64_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+criterion_group!(init_jsf64_benches, bench_jsf64_init_with_no_seed, bench_jsf64_init_with_seed);
+criterion_group!(generate_jsf64_benches, bench_jsf64_get_randoms);
+criterion_group!(fill_jsf64_benches, bench_jsf64_fill);
+
+criterion_main!(init_jsf64_benches, generate_jsf64_benches, fill_jsf64_benches);
@@ -0,0 +1,106 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use random_numbers::prelude::*;
+
+// the fill benchmarks report their throughput, in GiB/s
+const BUFFER_BYTES: usize = 64 * 1024;
+
+fn bench_philox4x32_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("philox4x32_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Philox4x32)));
+}
+
+fn bench_philox4x32_init_with_seed(c: &mut Criterion) {
+    c.bench_function("philox4x32_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Philox4x32, black_box(0))));
+}
+
+fn bench_philox4x32_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Philox4x32, black_box(0));
+    
+    c.bench_function("philox4x32_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("philox4x32_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("philox4x32_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("philox4x32_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("philox4x32_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("philox4x32_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("philox4x32_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("philox4x32_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("philox4x32_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("philox4x32_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("philox4x32_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("philox4x32_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("philox4x32_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_philox4x32_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Philox4x32, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("philox4x32_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_threefry4x64_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("threefry4x64_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Threefry4x64)));
+}
+
+fn bench_threefry4x64_init_with_seed(c: &mut Criterion) {
+    c.bench_function("threefry4x64_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Threefry4x64, black_box(0))));
+}
+
+fn bench_threefry4x64_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Threefry4x64, black_box(0));
+    
+    c.bench_function("threefry4x64_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("threefry4x64_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("threefry4x64_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("threefry4x64_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("threefry4x64_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("threefry4x64_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("threefry4x64_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("threefry4x64_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("threefry4x64_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("threefry4x64_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("threefry4x64_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("threefry4x64_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("threefry4x64_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_threefry4x64_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Threefry4x64, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("threefry4x64_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+criterion_group!(init_philox4x32_benches, bench_philox4x32_init_with_no_seed, bench_philox4x32_init_with_seed);
+criterion_group!(generate_philox4x32_benches, bench_philox4x32_get_randoms);
+criterion_group!(fill_philox4x32_benches, bench_philox4x32_fill);
+criterion_group!(init_threefry4x64_benches, bench_threefry4x64_init_with_no_seed, bench_threefry4x64_init_with_seed);
+criterion_group!(generate_threefry4x64_benches, bench_threefry4x64_get_randoms);
+criterion_group!(fill_threefry4x64_benches, bench_threefry4x64_fill);
+
+criterion_main!(
+    init_philox4x32_benches,
+    generate_philox4x32_benches,
+    fill_philox4x32_benches,
+    init_threefry4x64_benches,
+    generate_threefry4x64_benches,
+    fill_threefry4x64_benches,
+);
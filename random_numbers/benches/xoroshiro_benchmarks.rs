@@ -0,0 +1,298 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use random_numbers::prelude::*;
+
+// the fill benchmarks report their throughput, in GiB/s
+const BUFFER_BYTES: usize = 64 * 1024;
+
+fn bench_xoroshiro_128p_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128p_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoroshiro128P)));
+}
+
+fn bench_xoroshiro_128p_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128p_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128P, black_box(0))));
+}
+
+fn bench_xoroshiro_128p_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128P, black_box(0));
+    
+    c.bench_function("xoroshiro_128p_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoroshiro_128p_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoroshiro_128p_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoroshiro_128p_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoroshiro_128p_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoroshiro_128p_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoroshiro_128p_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoroshiro_128p_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoroshiro_128p_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoroshiro_128p_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoroshiro_128p_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoroshiro_128p_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoroshiro_128p_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoroshiro_128p_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128P, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoroshiro_128p_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoroshiro_128pp_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128pp_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoroshiro128PP)));
+}
+
+fn bench_xoroshiro_128pp_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128pp_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128PP, black_box(0))));
+}
+
+fn bench_xoroshiro_128pp_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128PP, black_box(0));
+    
+    c.bench_function("xoroshiro_128pp_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoroshiro_128pp_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoroshiro_128pp_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoroshiro_128pp_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoroshiro_128pp_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoroshiro_128pp_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoroshiro_128pp_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoroshiro_128pp_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoroshiro_128pp_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoroshiro_128pp_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoroshiro_128pp_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoroshiro_128pp_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoroshiro_128pp_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoroshiro_128pp_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128PP, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoroshiro_128pp_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoroshiro_128ss_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128ss_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoroshiro128SS)));
+}
+
+fn bench_xoroshiro_128ss_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoroshiro_128ss_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128SS, black_box(0))));
+}
+
+fn bench_xoroshiro_128ss_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128SS, black_box(0));
+    
+    c.bench_function("xoroshiro_128ss_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoroshiro_128ss_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoroshiro_128ss_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoroshiro_128ss_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoroshiro_128ss_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoroshiro_128ss_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoroshiro_128ss_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoroshiro_128ss_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoroshiro_128ss_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoroshiro_128ss_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoroshiro_128ss_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoroshiro_128ss_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoroshiro_128ss_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoroshiro_128ss_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoroshiro128SS, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoroshiro_128ss_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoshiro_256p_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_256p_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro256P)));
+}
+
+fn bench_xoshiro_256p_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_256p_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256P, black_box(0))));
+}
+
+fn bench_xoshiro_256p_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256P, black_box(0));
+    
+    c.bench_function("xoshiro_256p_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoshiro_256p_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoshiro_256p_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoshiro_256p_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoshiro_256p_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoshiro_256p_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoshiro_256p_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoshiro_256p_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoshiro_256p_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoshiro_256p_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoshiro_256p_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoshiro_256p_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoshiro_256p_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoshiro_256p_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256P, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_256p_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoshiro_256pp_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_256pp_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro256PP)));
+}
+
+fn bench_xoshiro_256pp_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_256pp_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256PP, black_box(0))));
+}
+
+fn bench_xoshiro_256pp_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256PP, black_box(0));
+    
+    c.bench_function("xoshiro_256pp_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoshiro_256pp_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoshiro_256pp_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoshiro_256pp_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoshiro_256pp_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoshiro_256pp_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoshiro_256pp_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoshiro_256pp_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoshiro_256pp_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoshiro_256pp_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoshiro_256pp_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoshiro_256pp_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoshiro_256pp_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoshiro_256pp_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256PP, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_256pp_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoshiro_512ss_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_512ss_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro512SS)));
+}
+
+fn bench_xoshiro_512ss_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_512ss_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro512SS, black_box(0))));
+}
+
+fn bench_xoshiro_512ss_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro512SS, black_box(0));
+    
+    c.bench_function("xoshiro_512ss_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoshiro_512ss_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoshiro_512ss_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoshiro_512ss_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoshiro_512ss_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoshiro_512ss_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoshiro_512ss_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoshiro_512ss_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoshiro_512ss_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoshiro_512ss_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoshiro_512ss_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoshiro_512ss_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoshiro_512ss_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoshiro_512ss_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro512SS, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_512ss_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+criterion_group!(init_xoroshiro_128p_benches, bench_xoroshiro_128p_init_with_no_seed, bench_xoroshiro_128p_init_with_seed);
+criterion_group!(generate_xoroshiro_128p_benches, bench_xoroshiro_128p_get_randoms);
+criterion_group!(fill_xoroshiro_128p_benches, bench_xoroshiro_128p_fill);
+criterion_group!(init_xoroshiro_128pp_benches, bench_xoroshiro_128pp_init_with_no_seed, bench_xoroshiro_128pp_init_with_seed);
+criterion_group!(generate_xoroshiro_128pp_benches, bench_xoroshiro_128pp_get_randoms);
+criterion_group!(fill_xoroshiro_128pp_benches, bench_xoroshiro_128pp_fill);
+criterion_group!(init_xoroshiro_128ss_benches, bench_xoroshiro_128ss_init_with_no_seed, bench_xoroshiro_128ss_init_with_seed);
+criterion_group!(generate_xoroshiro_128ss_benches, bench_xoroshiro_128ss_get_randoms);
+criterion_group!(fill_xoroshiro_128ss_benches, bench_xoroshiro_128ss_fill);
+criterion_group!(init_xoshiro_256p_benches, bench_xoshiro_256p_init_with_no_seed, bench_xoshiro_256p_init_with_seed);
+criterion_group!(generate_xoshiro_256p_benches, bench_xoshiro_256p_get_randoms);
+criterion_group!(fill_xoshiro_256p_benches, bench_xoshiro_256p_fill);
+criterion_group!(init_xoshiro_256pp_benches, bench_xoshiro_256pp_init_with_no_seed, bench_xoshiro_256pp_init_with_seed);
+criterion_group!(generate_xoshiro_256pp_benches, bench_xoshiro_256pp_get_randoms);
+criterion_group!(fill_xoshiro_256pp_benches, bench_xoshiro_256pp_fill);
+criterion_group!(init_xoshiro_512ss_benches, bench_xoshiro_512ss_init_with_no_seed, bench_xoshiro_512ss_init_with_seed);
+criterion_group!(generate_xoshiro_512ss_benches, bench_xoshiro_512ss_get_randoms);
+criterion_group!(fill_xoshiro_512ss_benches, bench_xoshiro_512ss_fill);
+
+criterion_main!(
+    init_xoroshiro_128p_benches,
+    generate_xoroshiro_128p_benches,
+    fill_xoroshiro_128p_benches,
+    init_xoroshiro_128pp_benches,
+    generate_xoroshiro_128pp_benches,
+    fill_xoroshiro_128pp_benches,
+    init_xoroshiro_128ss_benches,
+    generate_xoroshiro_128ss_benches,
+    fill_xoroshiro_128ss_benches,
+    init_xoshiro_256p_benches,
+    generate_xoshiro_256p_benches,
+    fill_xoshiro_256p_benches,
+    init_xoshiro_256pp_benches,
+    generate_xoshiro_256pp_benches,
+    fill_xoshiro_256pp_benches,
+    init_xoshiro_512ss_benches,
+    generate_xoshiro_512ss_benches,
+    fill_xoshiro_512ss_benches,
+);
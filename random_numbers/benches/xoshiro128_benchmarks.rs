@@ -0,0 +1,131 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use random_numbers::prelude::*;
+
+// the fill benchmarks report their throughput, in GiB/s
+const BUFFER_BYTES: usize = 64 * 1024;
+
+fn bench_xoshiro_128ss_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_128ss_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro128SS)));
+}
+
+fn bench_xoshiro_128ss_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_128ss_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128SS, black_box(0))));
+}
+
+fn bench_xoshiro_128ss_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128SS, black_box(0));
+    
+    c.bench_function("xoshiro_128ss_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoshiro_128ss_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoshiro_128ss_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoshiro_128ss_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoshiro_128ss_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoshiro_128ss_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoshiro_128ss_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoshiro_128ss_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoshiro_128ss_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoshiro_128ss_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoshiro_128ss_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoshiro_128ss_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoshiro_128ss_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoshiro_128ss_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128SS, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_128ss_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+fn bench_xoshiro_128pp_init_with_no_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_128pp_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro128PP)));
+}
+
+fn bench_xoshiro_128pp_init_with_seed(c: &mut Criterion) {
+    c.bench_function("xoshiro_128pp_init_with_seed", |b| b.iter(|| random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128PP, black_box(0))));
+}
+
+fn bench_xoshiro_128pp_get_randoms(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128PP, black_box(0));
+    
+    c.bench_function("xoshiro_128pp_get_random_bools", |b| b.iter(|| rnd.next_bool()));
+    c.bench_function("xoshiro_128pp_get_random_u8s", |b| b.iter(|| rnd.next_u8()));
+    c.bench_function("xoshiro_128pp_get_random_i8s", |b| b.iter(|| rnd.next_i8()));
+    c.bench_function("xoshiro_128pp_get_random_u16s", |b| b.iter(|| rnd.next_u16()));
+    c.bench_function("xoshiro_128pp_get_random_i16s", |b| b.iter(|| rnd.next_i16()));
+    c.bench_function("xoshiro_128pp_get_random_u32s", |b| b.iter(|| rnd.next_u32()));
+    c.bench_function("xoshiro_128pp_get_random_i32s", |b| b.iter(|| rnd.next_i32()));
+    c.bench_function("xoshiro_128pp_get_random_u64s", |b| b.iter(|| rnd.next_u64()));
+    c.bench_function("xoshiro_128pp_get_random_i64s", |b| b.iter(|| rnd.next_i64()));
+    c.bench_function("xoshiro_128pp_get_random_u128s", |b| b.iter(|| rnd.next_u128()));
+    c.bench_function("xoshiro_128pp_get_random_i128s", |b| b.iter(|| rnd.next_i128()));
+    c.bench_function("xoshiro_128pp_get_random_f32s", |b| b.iter(|| rnd.next_f32()));
+    c.bench_function("xoshiro_128pp_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
+}
+
+fn bench_xoshiro_128pp_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro128PP, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_128pp_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
+// compares the native 32-bit paths with the 64-bit generators, which throw away half of each draw for a u32.  Uses
+// the concrete types rather than a Box<dyn RandomNumber>, so the overridden methods can be inlined.
+fn bench_u32_f32_paths<R: RandomNumber + RandomNumberInit>(c: &mut Criterion, name: &str) {
+    let mut rnd = R::from_seed(black_box(0));
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+
+    let mut group = c.benchmark_group("u32_f32_paths");
+    group.bench_function(format!("{}_u32s", name), |b| b.iter(|| rnd.next_u32()));
+    group.bench_function(format!("{}_f32s", name), |b| b.iter(|| rnd.next_f32()));
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function(format!("{}_fill_u32s", name), |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.finish();
+}
+
+fn bench_compare_u32_f32_paths(c: &mut Criterion) {
+    bench_u32_f32_paths::<Xoshiro128SS>(c, "xoshiro_128ss");
+    bench_u32_f32_paths::<Xoshiro128PP>(c, "xoshiro_128pp");
+    bench_u32_f32_paths::<Pcg32>(c, "pcg32");
+    bench_u32_f32_paths::<Xoshiro256SS>(c, "xoshiro_256ss");
+    bench_u32_f32_paths::<Xoshiro256PP>(c, "xoshiro_256pp");
+    bench_u32_f32_paths::<WyRand>(c, "wyrand");
+}
+
+criterion_group!(init_xoshiro_128ss_benches, bench_xoshiro_128ss_init_with_no_seed, bench_xoshiro_128ss_init_with_seed);
+criterion_group!(generate_xoshiro_128ss_benches, bench_xoshiro_128ss_get_randoms);
+criterion_group!(fill_xoshiro_128ss_benches, bench_xoshiro_128ss_fill);
+criterion_group!(init_xoshiro_128pp_benches, bench_xoshiro_128pp_init_with_no_seed, bench_xoshiro_128pp_init_with_seed);
+criterion_group!(generate_xoshiro_128pp_benches, bench_xoshiro_128pp_get_randoms);
+criterion_group!(fill_xoshiro_128pp_benches, bench_xoshiro_128pp_fill);
+criterion_group!(compare_u32_f32_benches, bench_compare_u32_f32_paths);
+
+criterion_main!(
+    init_xoshiro_128ss_benches,
+    generate_xoshiro_128ss_benches,
+    fill_xoshiro_128ss_benches,
+    init_xoshiro_128pp_benches,
+    generate_xoshiro_128pp_benches,
+    fill_xoshiro_128pp_benches,
+    compare_u32_f32_benches,
+);
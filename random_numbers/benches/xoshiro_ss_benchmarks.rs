@@ -1,7 +1,10 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 
 use random_numbers::prelude::*;
 
+// the fill benchmarks report their throughput, in GiB/s
+const BUFFER_BYTES: usize = 64 * 1024;
+
 fn bench_xoshiro_ss_init_with_no_seed(c: &mut Criterion) {
     c.bench_function("xoshiro_ss_init_with_no_seed", |b| b.iter(|| random_numbers::new(RandomNumberAlgorithm::Xoshiro256SS)));
 }
@@ -28,7 +31,24 @@ fn bench_xoshiro_ss_get_randoms(c: &mut Criterion) {
     c.bench_function("xoshiro_ss_get_random_f64s", |b| b.iter(|| rnd.next_f64()));
 }
 
+fn bench_xoshiro_ss_fill(c: &mut Criterion) {
+    let mut rnd = random_numbers::from_seed(RandomNumberAlgorithm::Xoshiro256SS, black_box(0));
+    let mut bytes = vec![0u8; BUFFER_BYTES];
+    let mut u32s = vec![0u32; BUFFER_BYTES / 4];
+    let mut u64s = vec![0u64; BUFFER_BYTES / 8];
+    let mut f64s = vec![0f64; BUFFER_BYTES / 8];
+
+    let mut group = c.benchmark_group("xoshiro_ss_fill");
+    group.throughput(Throughput::Bytes(BUFFER_BYTES as u64));
+    group.bench_function("bytes", |b| b.iter(|| rnd.fill_bytes(&mut bytes)));
+    group.bench_function("u32s", |b| b.iter(|| rnd.fill_u32(&mut u32s)));
+    group.bench_function("u64s", |b| b.iter(|| rnd.fill_u64(&mut u64s)));
+    group.bench_function("f64s", |b| b.iter(|| rnd.fill_f64(&mut f64s)));
+    group.finish();
+}
+
 criterion_group!(init_xoshiro_ss_benches, bench_xoshiro_ss_init_with_no_seed, bench_xoshiro_ss_init_with_seed);
 criterion_group!(generate_xoshiro_ss_benches, bench_xoshiro_ss_get_randoms);
+criterion_group!(fill_xoshiro_ss_benches, bench_xoshiro_ss_fill);
 
-criterion_main!(init_xoshiro_ss_benches, generate_xoshiro_ss_benches);
+criterion_main!(init_xoshiro_ss_benches, generate_xoshiro_ss_benches, fill_xoshiro_ss_benches);
@@ -0,0 +1,573 @@
+use std::fmt;
+use std::io;
+
+use crate::seeds;
+use crate::CryptoRandomNumber;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    // "expand 32-byte k" as little-endian words
+    pub const SIGMA: [u32; 4] = [0x61707865, 0x3320646E, 0x79622D32, 0x6B206574];
+
+    pub const BLOCK_WORDS: usize = 16;
+    pub const KEY_BYTES: usize = 32;
+}
+
+/// The ChaCha stream cipher, used as a cryptographically secure random number generator.  `ROUNDS` is the number
+/// of rounds, and can only be 8, 12 or 20 (see [`ChaCha8`], [`ChaCha12`] and [`ChaCha20`]).
+/// 
+/// The state is laid out as in Bernstein's original design: a 256-bit key, a 64-bit block counter and a 64-bit
+/// stream (nonce), so a single key can produce 2^64 independent streams of 2^70 bytes each.
+#[derive(Clone)]
+pub struct ChaCha<const ROUNDS: usize> where ChaChaRounds<ROUNDS>: ValidRounds {
+    key: [u32; 8],
+    counter: u64,
+    stream: [u32; 2],
+    block: [u32; constants::BLOCK_WORDS],
+    index: usize
+}
+
+/// A round count for [`ChaCha`], which only implements [`ValidRounds`] for 8, 12 and 20.
+pub struct ChaChaRounds<const ROUNDS: usize>;
+
+/// The round counts [`ChaCha`] is defined for.  It's sealed, since fewer rounds wouldn't be cryptographically
+/// secure and each round count needs its own state format.
+/// 
+/// ```compile_fail
+/// use random_numbers::prelude::*;
+/// 
+/// let chacha = ChaCha::<7>::from_seed(0);
+/// ```
+pub trait ValidRounds: sealed::Sealed {
+    #[doc(hidden)]
+    const STATE_TAG: u8;
+}
+
+impl ValidRounds for ChaChaRounds<8> {
+    const STATE_TAG: u8 = tags::CHACHA8;
+}
+
+impl ValidRounds for ChaChaRounds<12> {
+    const STATE_TAG: u8 = tags::CHACHA12;
+}
+
+impl ValidRounds for ChaChaRounds<20> {
+    const STATE_TAG: u8 = tags::CHACHA20;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::ChaChaRounds<8> {}
+    impl Sealed for super::ChaChaRounds<12> {}
+    impl Sealed for super::ChaChaRounds<20> {}
+}
+
+/// ChaCha with 8 rounds.  The fastest variant, with a smaller (but still comfortable) security margin.
+pub type ChaCha8 = ChaCha<8>;
+
+/// ChaCha with 12 rounds.  A good compromise between speed and security margin.
+pub type ChaCha12 = ChaCha<12>;
+
+/// ChaCha with 20 rounds, as standardized in RFC 8439.
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const ROUNDS: usize> ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    /// Creates a generator from a 256-bit key and a 64-bit stream number.  Different streams with the same key
+    /// produce independent sequences.
+    /// 
+    /// This is the only way to create a generator suitable for cryptographic use from a known key; the `u64` seed
+    /// accepted by [`RandomNumberInit::from_seed`] only gives 2^64 possible keys.
+    pub fn from_key(key: [u8; constants::KEY_BYTES], stream: u64) -> ChaCha<ROUNDS> {
+        let mut key_words = [0; 8];
+        for (word, bytes) in key_words.iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        ChaCha {
+            key: key_words,
+            counter: 0,
+            stream: [stream as u32, (stream >> 32) as u32],
+            block: [0; constants::BLOCK_WORDS],
+            // forces a new block to be generated on the first call
+            index: constants::BLOCK_WORDS,
+        }
+    }
+}
+
+// never print the key or the buffered output
+impl<const ROUNDS: usize> fmt::Debug for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ChaCha{} {{ .. }}", ROUNDS)
+    }
+}
+
+impl<const ROUNDS: usize> RandomNumberInit for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    const SEED_BYTES: usize = constants::KEY_BYTES + 8;
+
+    /// Creates a new generator with its key and stream filled from the operating system.  Note that
+    /// [`RandomNumberInit::from_time`] is NOT suitable for cryptographic use.
+    fn try_new() -> io::Result<ChaCha<ROUNDS>> {
+        let mut key = [0; constants::KEY_BYTES];
+        let mut stream = [0];
+
+        seeds::os_random_bytes(&mut key)?;
+        seeds::os_random_u64s(&mut stream)?;
+
+        Ok(ChaCha::from_key(key, stream[0]))
+    }
+
+    /// Expands the seed into a key using SplitMix.  Useful for repeatable sequences, but NOT suitable for
+    /// cryptographic use, since there are only 2^64 possible keys.  Use [`ChaCha::from_key`] instead.
+    fn from_seed(seed: u64) -> ChaCha<ROUNDS> {
+        let mut split_mix = SplitMix::from_seed(seed);
+        let mut key = [0; constants::KEY_BYTES];
+
+        for bytes in key.chunks_exact_mut(8) {
+            bytes.copy_from_slice(&split_mix.next_u64().to_le_bytes());
+        }
+
+        ChaCha::from_key(key, 0)
+    }
+
+    /// The first 32 bytes are the key, the last 8 select the stream.  The block counter starts at 0.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<ChaCha<ROUNDS>, SeedError> {
+        if bytes.len() != Self::SEED_BYTES {
+            return Err(SeedError::WrongLength { expected: Self::SEED_BYTES, actual: bytes.len() });
+        }
+
+        let mut key = [0; constants::KEY_BYTES];
+        let mut stream = [0; 8];
+
+        key.copy_from_slice(&bytes[..constants::KEY_BYTES]);
+        stream.copy_from_slice(&bytes[constants::KEY_BYTES..]);
+
+        Ok(ChaCha::from_key(key, u64::from_le_bytes(stream)))
+    }
+}
+
+impl<const ROUNDS: usize> RandomNumberState for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(ChaChaRounds::<ROUNDS>::STATE_TAG);
+        writer.write_u32s(&self.key);
+        writer.write_u64(self.counter);
+        writer.write_u32s(&self.stream);
+        writer.write_u64(self.index as u64);
+
+        writer.finish()
+    }
+
+    /// The buffered block isn't part of the saved state, it's regenerated from the key and counter.
+    fn restore_state(state: &[u8]) -> Result<ChaCha<ROUNDS>, StateError> {
+        let mut reader = StateReader::new(state, ChaChaRounds::<ROUNDS>::STATE_TAG)?;
+        let mut key = [0; 8];
+        let mut stream = [0; 2];
+
+        reader.read_u32s(&mut key)?;
+        let counter = reader.read_u64()?;
+        reader.read_u32s(&mut stream)?;
+        let index = reader.read_index(constants::BLOCK_WORDS)?;
+        reader.finish()?;
+
+        let mut chacha = ChaCha {
+            key,
+            counter,
+            stream,
+            block: [0; constants::BLOCK_WORDS],
+            index,
+        };
+
+        if index < constants::BLOCK_WORDS {
+            // the counter was incremented when the buffered block was generated
+            chacha.counter = counter.wrapping_sub(1);
+            generate_block(&mut chacha);
+            chacha.index = index;
+        }
+
+        Ok(chacha)
+    }
+}
+
+impl<const ROUNDS: usize> RandomNumber for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::BLOCK_WORDS {
+            generate_block(self);
+        }
+
+        let n = self.block[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    /// Copies out the keystream a block at a time, in byte order, so the output matches other ChaCha
+    /// implementations.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index >= constants::BLOCK_WORDS {
+                generate_block(self);
+            }
+
+            let count = ((constants::BLOCK_WORDS - self.index) * 4).min(dest.len() - filled);
+
+            for (chunk, n) in dest[filled..filled + count].chunks_mut(4).zip(&self.block[self.index..]) {
+                chunk.copy_from_slice(&n.to_le_bytes()[..chunk.len()]);
+            }
+
+            // a partly used word is thrown away, like next_u32 would
+            self.index += count.div_ceil(4);
+            filled += count;
+        }
+    }
+
+    fn fill_u32(&mut self, dest: &mut [u32]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index >= constants::BLOCK_WORDS {
+                generate_block(self);
+            }
+
+            let count = (constants::BLOCK_WORDS - self.index).min(dest.len() - filled);
+
+            dest[filled..filled + count].copy_from_slice(&self.block[self.index..self.index + count]);
+
+            self.index += count;
+            filled += count;
+        }
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl<const ROUNDS: usize> RandomNumberAdvance for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    /// Each step produces one [`RandomNumber::next_u32`].  The block counter is 64 bits, so each stream has a
+    /// period of 2^68 and `delta` wraps modulo that.
+    fn advance(&mut self, delta: u128) {
+        // the counter of the block holding the next word, and the word's position in it
+        let (counter, index) = if self.index < constants::BLOCK_WORDS {
+            (self.counter.wrapping_sub(1), self.index)
+        } else {
+            (self.counter, 0)
+        };
+
+        let words = index as u128 + delta % constants::BLOCK_WORDS as u128;
+        let blocks = delta / constants::BLOCK_WORDS as u128 + words / constants::BLOCK_WORDS as u128;
+
+        self.counter = counter.wrapping_add(blocks as u64);
+        self.index = constants::BLOCK_WORDS;
+
+        let index = (words % constants::BLOCK_WORDS as u128) as usize;
+
+        if index > 0 {
+            generate_block(self);
+            self.index = index;
+        }
+    }
+}
+
+impl<const ROUNDS: usize> CryptoRandomNumber for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {}
+
+fn generate_block<const ROUNDS: usize>(chacha: &mut ChaCha<ROUNDS>) where ChaChaRounds<ROUNDS>: ValidRounds {
+    let input = initial_state(chacha);
+    let mut x = input;
+
+    for _ in 0..(ROUNDS / 2) {
+        // column round
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+
+        // diagonal round
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+
+    for (out, (mixed, original)) in chacha.block.iter_mut().zip(x.iter().zip(input.iter())) {
+        *out = mixed.wrapping_add(*original);
+    }
+
+    chacha.counter = chacha.counter.wrapping_add(1);
+    chacha.index = 0;
+}
+
+fn initial_state<const ROUNDS: usize>(chacha: &ChaCha<ROUNDS>) -> [u32; constants::BLOCK_WORDS]
+where
+    ChaChaRounds<ROUNDS>: ValidRounds,
+{
+    let mut state = [0; constants::BLOCK_WORDS];
+
+    state[0..4].copy_from_slice(&constants::SIGMA);
+    state[4..12].copy_from_slice(&chacha.key);
+    state[12] = chacha.counter as u32;
+    state[13] = (chacha.counter >> 32) as u32;
+    state[14] = chacha.stream[0];
+    state[15] = chacha.stream[1];
+
+    state
+}
+
+fn quarter_round(x: &mut [u32; constants::BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keystream_bytes<const ROUNDS: usize>(chacha: &mut ChaCha<ROUNDS>, len: usize) -> Vec<u8>
+    where
+        ChaChaRounds<ROUNDS>: ValidRounds,
+    {
+        (0..(len / 4)).flat_map(|_| chacha.next_u32().to_le_bytes().to_vec()).collect()
+    }
+
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..(i + 2)], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn new_test() {
+        let mut chacha = ChaCha20::new();
+
+        chacha.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let chacha = ChaCha20::from_seed(0);
+
+        assert_eq!(chacha.key, [0, 0, 2065550767, 3793791033, 2713282036, 1853398634, 2148091215, 113532184]);
+        assert_eq!(chacha.counter, 0);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut chacha = ChaCha20::from_seed(0);
+
+        assert_eq!(chacha.next_u32(), 1697723682);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut chacha = ChaCha20::from_seed(0);
+
+        assert_eq!(chacha.next_u64(), 7291667695029186371);
+    }
+
+    #[test]
+    fn debug_hides_key_test() {
+        let chacha = ChaCha12::from_key([0xAB; 32], 0);
+
+        assert_eq!(format!("{:?}", chacha), "ChaCha12 { .. }");
+    }
+
+    #[test]
+    fn rfc_8439_block_function_test() {
+        // RFC 8439, section 2.3.2: key 00:01:..:1f, block counter 1, nonce 00:00:00:09:00:00:00:4a:00:00:00:00.
+        // The 32-bit counter and first nonce word map onto our 64-bit counter, the other two words onto the stream.
+        let mut key = [0; 32];
+        for (i, k) in key.iter_mut().enumerate() {
+            *k = i as u8;
+        }
+
+        let mut chacha = ChaCha20::from_key(key, 0x4A000000);
+        chacha.counter = 0x09000000_00000001;
+
+        let expected: [u32; 16] = [
+            0xE4E7F110, 0x15593BD1, 0x1FDD0F50, 0xC47120A3,
+            0xC7F4D1C7, 0x0368C033, 0x9AAA2204, 0x4E6CD4C3,
+            0x466482D2, 0x09AA9F07, 0x05D7C214, 0xA2028BD9,
+            0xD19C12B5, 0xB94E16DE, 0xE883D0CB, 0x4E3C50A2,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(chacha.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn rfc_8439_keystream_test() {
+        // RFC 8439, appendix A.1, test vectors #1 and #2: all zero key and nonce, block counters 0 and 1
+        let mut chacha = ChaCha20::from_key([0; 32], 0);
+
+        let expected = hex_to_bytes(concat!(
+            "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7",
+            "da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586",
+            "9f07e7be5551387a98ba977c732d080dcb0f29a048e3656912c6533e32ee7aed",
+            "29b721769ce64e43d57133b074d839d531ed1f28510afb45ace10a1f4b794d6f",
+        ));
+
+        assert_eq!(keystream_bytes(&mut chacha, 128), expected);
+    }
+
+    #[test]
+    fn chacha8_keystream_test() {
+        // draft-strombergson-chacha-test-vectors, TC1 with a 256-bit all zero key and IV, 8 rounds
+        let mut chacha = ChaCha8::from_key([0; 32], 0);
+
+        let expected = hex_to_bytes(concat!(
+            "3e00ef2f895f40d67f5bb8e81f09a5a12c840ec3ce9a7f3b181be188ef711a1e",
+            "984ce172b9216f419f445367456d5619314a42a3da86b001387bfdb80e0cfe42",
+        ));
+
+        assert_eq!(keystream_bytes(&mut chacha, 64), expected);
+    }
+
+    #[test]
+    fn chacha12_keystream_test() {
+        // draft-strombergson-chacha-test-vectors, TC1 with a 256-bit all zero key and IV, 12 rounds
+        let mut chacha = ChaCha12::from_key([0; 32], 0);
+
+        let expected = hex_to_bytes(concat!(
+            "9bf49a6a0755f953811fce125f2683d50429c3bb49e074147e0089a52eae155f",
+            "0564f879d27ae3c02ce82834acfa8c793a629f2ca0de6919610be82f411326be",
+        ));
+
+        assert_eq!(keystream_bytes(&mut chacha, 64), expected);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = [0; ChaCha20::SEED_BYTES];
+        bytes[..32].copy_from_slice(&hex_to_bytes("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"));
+        bytes[32..].copy_from_slice(&7u64.to_le_bytes());
+
+        let mut key = [0; 32];
+        key.copy_from_slice(&bytes[..32]);
+
+        let mut chacha = ChaCha20::from_seed_bytes(&bytes).unwrap();
+        let mut expected = ChaCha20::from_key(key, 7);
+
+        assert_eq!(chacha.next_u64(), expected.next_u64());
+        assert!(ChaCha20::from_seed_bytes(&bytes[..32]).is_err());
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut chacha = ChaCha8::from_seed(1);
+        let mut expected = ChaCha8::from_seed(1);
+
+        for delta in [0, 1, 2, 15, 16, 17, 31, 1000] {
+            chacha.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u32();
+            }
+
+            assert_eq!(chacha.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut chacha = ChaCha20::from_seed(1);
+        let mut expected = ChaCha20::from_seed(1);
+
+        chacha.next_u32();
+        expected.next_u32();
+        chacha.advance(1 << 68);
+
+        for _ in 0..100 {
+            assert_eq!(chacha.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut chacha = ChaCha20::from_seed(1);
+
+        for _ in 0..5 {
+            chacha.next_u64();
+        }
+
+        let state = chacha.save_state();
+        let mut restored = ChaCha20::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), chacha.next_u64());
+        }
+
+        assert_eq!(chacha.snapshot(), Some(restored.save_state()));
+        assert!(ChaCha20::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(ChaCha20::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn fill_bytes_test() {
+        let mut chacha = ChaCha20::from_seed(0);
+        let mut expected = ChaCha20::from_seed(0);
+        let mut bytes = [0; 150];
+
+        chacha.next_u32();
+        expected.next_u32();
+        chacha.fill_bytes(&mut bytes);
+
+        for chunk in bytes.chunks(4) {
+            assert_eq!(chunk, &expected.next_u32().to_le_bytes()[..chunk.len()]);
+        }
+
+        assert_eq!(chacha.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn fill_u32_test() {
+        let mut chacha = ChaCha8::from_seed(0);
+        let mut expected = ChaCha8::from_seed(0);
+        let mut ns = [0; 40];
+
+        chacha.next_u32();
+        expected.next_u32();
+        chacha.fill_u32(&mut ns);
+
+        for n in ns.iter() {
+            assert_eq!(*n, expected.next_u32());
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An infinite iterator of random values, borrowing its generator.  Returned by methods like
+/// [`crate::RandomNumber::iter_u64`]; use [`Iterator::take`] to get a finite number of values.
+pub struct RandomIter<'a, R: ?Sized, T> {
+    random_number: &'a mut R,
+    next: fn(&mut R) -> T
+}
+
+impl<'a, R: ?Sized, T> RandomIter<'a, R, T> {
+    pub(crate) fn new(random_number: &'a mut R, next: fn(&mut R) -> T) -> RandomIter<'a, R, T> {
+        RandomIter { random_number, next }
+    }
+}
+
+impl<'a, R: ?Sized, T> Iterator for RandomIter<'a, R, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some((self.next)(self.random_number))
+    }
+
+    // never ends, like std::iter::repeat, so `take(n)` reports exactly n
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<'a, R: ?Sized, T> FusedIterator for RandomIter<'a, R, T> {}
+
+impl<'a, R: fmt::Debug + ?Sized, T> fmt::Debug for RandomIter<'a, R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RandomIter").field("random_number", &self.random_number).finish()
+    }
+}
@@ -0,0 +1,187 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    // Jenkins' raninit() starts `a` at this constant, which rules out the known short cycles
+    pub const SEED_A: u64 = 0xF1EA5EED;
+    pub const INIT_CALLS: usize = 20;
+}
+
+/// Bob Jenkins' [small noncryptographic PRNG](https://burtleburtle.net/bob/rand/smallprng.html), often called JSF
+/// ("Jenkins small fast"), in its 64-bit version: 256 bits of chaotic state with no multiplications.
+#[derive(Debug, Clone)]
+pub struct Jsf64 {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64
+}
+
+impl RandomNumberInit for Jsf64 {
+    const SEED_BYTES: usize = 32;
+
+    fn try_new() -> io::Result<Jsf64> {
+        let mut seed = [0];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(jsf64_from_seed(seed[0]))
+    }
+
+    fn from_seed(seed: u64) -> Jsf64 {
+        jsf64_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets `a`, `b`, `c` and `d` directly, without Jenkins' initialization.  Arbitrary states can fall into
+    /// short cycles, so prefer [`RandomNumberInit::from_seed`].
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Jsf64, SeedError> {
+        let mut state = [0; 4];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(Jsf64 {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            d: state[3],
+        })
+    }
+}
+
+impl RandomNumberState for Jsf64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::JSF64);
+        writer.write_u64s(&[self.a, self.b, self.c, self.d]);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Jsf64, StateError> {
+        let mut reader = StateReader::new(state, tags::JSF64)?;
+        let mut state = [0; 4];
+
+        reader.read_nonzero_u64s(&mut state)?;
+        reader.finish()?;
+
+        Ok(Jsf64 {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            d: state[3],
+        })
+    }
+}
+
+impl RandomNumber for Jsf64 {
+    fn next_u64(&mut self) -> u64 {
+        let e = self.a.wrapping_sub(self.b.rotate_left(7));
+
+        self.a = self.b ^ self.c.rotate_left(13);
+        self.b = self.c.wrapping_add(self.d.rotate_left(37));
+        self.c = self.d.wrapping_add(e);
+        self.d = e.wrapping_add(self.a);
+
+        self.d
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn jsf64_from_split_mix(split_mix: &mut SplitMix) -> Jsf64 {
+    jsf64_from_seed(split_mix.next_u64())
+}
+
+// matches raninit(x, seed) from the reference implementation
+fn jsf64_from_seed(seed: u64) -> Jsf64 {
+    let mut jsf = Jsf64 {
+        a: constants::SEED_A,
+        b: seed,
+        c: seed,
+        d: seed,
+    };
+
+    for _ in 0..constants::INIT_CALLS {
+        jsf.next_u64();
+    }
+
+    jsf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut jsf = Jsf64::new();
+
+        jsf.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let jsf = Jsf64::from_seed(1);
+        let expected = jsf64_from_seed(SplitMix::from_seed(1).next_u64());
+
+        assert_eq!(jsf.save_state(), expected.save_state());
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from the 64-bit reference implementation, after raninit(x, 42)
+        let mut jsf = jsf64_from_seed(42);
+
+        let expected: [u64; 6] = [
+            11921485425870369842,
+            6950967119895308506,
+            3738120138616583258,
+            12954590915796698081,
+            16833194306754848519,
+            6352690194663110999,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(jsf.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let expected = jsf64_from_seed(42);
+        let bytes: Vec<u8> = [expected.a, expected.b, expected.c, expected.d].iter().flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut jsf = Jsf64::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(jsf.next_u64(), 11921485425870369842);
+        assert_eq!(Jsf64::from_seed_bytes(&[0; 32]).unwrap_err(), SeedError::AllZero);
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut jsf = Jsf64::from_seed(1);
+
+        jsf.next_u64();
+
+        let state = jsf.save_state();
+        let mut restored = Jsf64::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), jsf.next_u64());
+        }
+
+        assert_eq!(jsf.snapshot(), Some(restored.save_state()));
+        assert!(Jsf64::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Jsf64::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::RandomNumber;
+
+mod constants {
+    // glibc's default TYPE_3 generator: x[i] = x[i - 3] + x[i - 31]
+    pub const DEGREE: usize = 31;
+    pub const SEPARATION: usize = 3;
+
+    // srandom() discards this many outputs after seeding
+    pub const INIT_CALLS: usize = 10 * DEGREE;
+
+    // Schrage's method for 16807 * x % (2^31 - 1), as srandom() computes it
+    pub const MULTIPLIER: i64 = 16807;
+    pub const QUOTIENT: i64 = 127773;
+    pub const REMAINDER: i64 = 2836;
+    pub const MODULUS: i64 = 2147483647;
+}
+
+/// glibc's `rand()` and `random()`, for reproducing results from C programs built against glibc.
+/// 
+/// Despite the name of this module it isn't an LCG: glibc's default generator is an additive lagged Fibonacci
+/// generator, `x[i] = x[i - 3] + x[i - 31]`, whose 31 words of state are filled by the MINSTD LCG when it's seeded.
+/// `rand()` returns the top 31 bits of each word.
+/// 
+/// [`RandomNumber::next_u32`] returns whole words, including the low bit glibc throws away.
+#[derive(Debug, Clone)]
+pub struct GlibcRand {
+    state: [u32; constants::DEGREE],
+    front: usize,
+    rear: usize
+}
+
+impl GlibcRand {
+    /// Seeds the generator like `srand(seed)`.  A seed of zero is replaced with one, as glibc does.
+    pub fn srand(seed: u32) -> GlibcRand {
+        let seed = if seed == 0 { 1 } else { seed };
+        let mut state = [0; constants::DEGREE];
+        // glibc stores the seed in an int32_t, so seeds over 2^31 start negative
+        let mut word = seed as i32 as i64;
+
+        state[0] = seed;
+
+        for n in state.iter_mut().skip(1) {
+            let hi = word / constants::QUOTIENT;
+            let lo = word % constants::QUOTIENT;
+
+            word = constants::MULTIPLIER * lo - constants::REMAINDER * hi;
+
+            if word < 0 {
+                word += constants::MODULUS;
+            }
+
+            *n = word as u32;
+        }
+
+        let mut glibc_rand = GlibcRand {
+            state,
+            front: constants::SEPARATION,
+            rear: 0,
+        };
+
+        for _ in 0..constants::INIT_CALLS {
+            glibc_rand.next_u32();
+        }
+
+        glibc_rand
+    }
+
+    /// Returns a number in `[0, 2^31)`, like `rand()`.
+    pub fn rand(&mut self) -> i32 {
+        (self.next_u32() >> 1) as i32
+    }
+}
+
+impl RandomNumber for GlibcRand {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let n = self.state[self.front].wrapping_add(self.state[self.rear]);
+
+        self.state[self.front] = n;
+        self.front = (self.front + 1) % constants::DEGREE;
+        self.rear = (self.rear + 1) % constants::DEGREE;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // values from rand() in glibc 2.36
+    #[test]
+    fn rand_test() {
+        let mut glibc_rand = GlibcRand::srand(1);
+
+        let expected: [i32; 6] = [1804289383, 846930886, 1681692777, 1714636915, 1957747793, 424238335];
+
+        for e in expected.iter() {
+            assert_eq!(glibc_rand.rand(), *e);
+        }
+    }
+
+    #[test]
+    fn srand_test() {
+        let mut glibc_rand = GlibcRand::srand(42);
+
+        let expected: [i32; 6] = [71876166, 708592740, 1483128881, 907283241, 442951012, 537146758];
+
+        for e in expected.iter() {
+            assert_eq!(glibc_rand.rand(), *e);
+        }
+
+        for _ in 6..9999 {
+            glibc_rand.rand();
+        }
+
+        assert_eq!(glibc_rand.rand(), 48510282);
+    }
+
+    #[test]
+    fn srand_large_seed_test() {
+        let mut glibc_rand = GlibcRand::srand(u32::MAX);
+
+        let expected: [i32; 3] = [254925627, 1205188300, 366127624];
+
+        for e in expected.iter() {
+            assert_eq!(glibc_rand.rand(), *e);
+        }
+    }
+
+    #[test]
+    fn srand_zero_test() {
+        assert_eq!(GlibcRand::srand(0).rand(), GlibcRand::srand(1).rand());
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut glibc_rand = GlibcRand::srand(1);
+        let mut expected = GlibcRand::srand(1);
+
+        assert_eq!(glibc_rand.next_u32() >> 1, expected.rand() as u32);
+    }
+}
@@ -0,0 +1,423 @@
+use crate::RandomNumber;
+
+use super::Lcg;
+
+mod constants {
+    // the seed is xored with this before it's used, so that seeds near zero don't start with small states
+    pub const SCRAMBLER: i64 = 0x5DEECE66D;
+
+    pub const STATE_BITS: u32 = 48;
+    pub const TWO_24_INVERSE: f32 = 1.0 / (1 << 24) as f32;
+
+    // fdlibm's e_log.c, which StrictMath.log() is specified to match
+    pub const LN2_HI: u64 = 0x3FE62E42FEE00000;
+    pub const LN2_LO: u64 = 0x3DEA39EF35793C76;
+    pub const TWO_54: f64 = 18014398509481984.0;
+    pub const LG: [u64; 7] = [
+        0x3FE5555555555593,
+        0x3FD999999997FA04,
+        0x3FD2492494229359,
+        0x3FCC71C51D8E78AF,
+        0x3FC7466496CB03DE,
+        0x3FC39A09D078C69F,
+        0x3FC2F112DF3E5244,
+    ];
+}
+
+/// `java.util.Random`, for reproducing results from Java programs.  It's the 48-bit LCG from [`Lcg::rand48`], with
+/// each method taking as many high bits of the state as it needs.
+///
+/// The methods match Java's bit for bit, including [`JavaRandom::next_gaussian`], which uses a port of fdlibm's
+/// `log` because Java uses `StrictMath.log`.  [`RandomNumber::next_u32`] and [`RandomNumber::next_u64`] are
+/// `nextInt()` and `nextLong()`.
+#[derive(Debug, Clone)]
+pub struct JavaRandom {
+    lcg: Lcg,
+    next_next_gaussian: Option<f64>
+}
+
+impl JavaRandom {
+    /// Seeds the generator like `new Random(seed)`.
+    pub fn new(seed: i64) -> JavaRandom {
+        JavaRandom {
+            lcg: Lcg::rand48((seed ^ constants::SCRAMBLER) as u64),
+            next_next_gaussian: None,
+        }
+    }
+
+    /// Reseeds the generator like `setSeed(seed)`, which also discards the second value from
+    /// [`JavaRandom::next_gaussian`].
+    pub fn set_seed(&mut self, seed: i64) {
+        *self = JavaRandom::new(seed);
+    }
+
+    /// Returns `nextInt()`.
+    pub fn next_int(&mut self) -> i32 {
+        self.next(32)
+    }
+
+    /// Returns `nextInt(bound)`, a number in `[0, bound)`.
+    ///
+    /// # Panics
+    ///
+    /// If `bound` isn't positive.
+    pub fn next_int_bound(&mut self, bound: i32) -> i32 {
+        assert!(bound > 0, "bound must be positive");
+
+        let mut r = self.next(31);
+        let m = bound - 1;
+
+        if bound & m == 0 {
+            return ((bound as i64 * r as i64) >> 31) as i32;
+        }
+
+        // rejects the values at the top of the range that would make the result biased, which is detected by the
+        // subtraction overflowing
+        let mut u = r;
+
+        loop {
+            r = u % bound;
+
+            if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                return r;
+            }
+
+            u = self.next(31);
+        }
+    }
+
+    /// Returns `nextLong()`.
+    pub fn next_long(&mut self) -> i64 {
+        ((self.next(32) as i64) << 32).wrapping_add(self.next(32) as i64)
+    }
+
+    /// Returns `nextBoolean()`.
+    pub fn next_boolean(&mut self) -> bool {
+        self.next(1) != 0
+    }
+
+    /// Returns `nextFloat()`, a number in `[0, 1)` with 24 bits of precision.
+    pub fn next_float(&mut self) -> f32 {
+        self.next(24) as f32 * constants::TWO_24_INVERSE
+    }
+
+    /// Returns `nextDouble()`, a number in `[0, 1)` with 53 bits of precision.
+    pub fn next_double(&mut self) -> f64 {
+        (((self.next(26) as i64) << 27) + self.next(27) as i64) as f64 * crate::TWO_53_INVERSE
+    }
+
+    /// Returns `nextGaussian()`, a normally distributed number with mean 0 and standard deviation 1.  Like Java,
+    /// this uses Marsaglia's polar method, which produces two numbers at a time, and the second is returned by the
+    /// next call.
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(gaussian) = self.next_next_gaussian.take() {
+            return gaussian;
+        }
+
+        loop {
+            let v1 = 2.0 * self.next_double() - 1.0;
+            let v2 = 2.0 * self.next_double() - 1.0;
+            let s = v1 * v1 + v2 * v2;
+
+            if s < 1.0 && s != 0.0 {
+                let multiplier = (-2.0 * strict_log(s) / s).sqrt();
+
+                self.next_next_gaussian = Some(v2 * multiplier);
+
+                return v1 * multiplier;
+            }
+        }
+    }
+
+    /// Fills `bytes` like `nextBytes(bytes)`, with the bytes of each `nextInt()` least significant first.
+    pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(4) {
+            let n = self.next_int().to_le_bytes();
+
+            chunk.copy_from_slice(&n[..chunk.len()]);
+        }
+    }
+
+    // the high `bits` bits of the next state
+    fn next(&mut self, bits: u32) -> i32 {
+        (self.lcg.next_state() >> (constants::STATE_BITS - bits)) as u32 as i32
+    }
+}
+
+impl RandomNumber for JavaRandom {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_boolean()
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.next_int() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.next_long() as u64
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        self.next_float()
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.next_double()
+    }
+
+    /// One `nextInt()` per element.  The default would split `nextLong()`, whose high half is off by one whenever
+    /// the low half is negative.
+    fn fill_u32(&mut self, dest: &mut [u32]) {
+        for n in dest.iter_mut() {
+            *n = self.next_u32();
+        }
+    }
+
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        self.next_bytes(bytes);
+    }
+}
+
+// a port of fdlibm's __ieee754_log, because f64::ln isn't guaranteed to round the same way
+fn strict_log(x: f64) -> f64 {
+    let ln2_hi = f64::from_bits(constants::LN2_HI);
+    let ln2_lo = f64::from_bits(constants::LN2_LO);
+    let [lg1, lg2, lg3, lg4, lg5, lg6, lg7] = constants::LG.map(f64::from_bits);
+
+    let mut x = x;
+    let mut hx = (x.to_bits() >> 32) as i32;
+    let lx = x.to_bits() as u32;
+    let mut k = 0;
+
+    if hx < 0x00100000 {
+        if (hx & 0x7FFFFFFF) as u32 | lx == 0 {
+            return f64::NEG_INFINITY;
+        }
+
+        if hx < 0 {
+            return f64::NAN;
+        }
+
+        // subnormal, scale x up
+        k -= 54;
+        x *= constants::TWO_54;
+        hx = (x.to_bits() >> 32) as i32;
+    }
+
+    if hx >= 0x7FF00000 {
+        return x + x;
+    }
+
+    k += (hx >> 20) - 1023;
+    hx &= 0x000FFFFF;
+
+    // normalizes x or x / 2 into [sqrt(2) / 2, sqrt(2))
+    let i = (hx + 0x95F64) & 0x100000;
+    x = f64::from_bits(((hx | (i ^ 0x3FF00000)) as u64) << 32 | (x.to_bits() & 0xFFFFFFFF));
+    k += i >> 20;
+
+    let f = x - 1.0;
+    let dk = k as f64;
+
+    if (0x000FFFFF & (2 + hx)) < 3 {
+        // |f| < 2^-20
+        if f == 0.0 {
+            return if k == 0 { 0.0 } else { dk * ln2_hi + dk * ln2_lo };
+        }
+
+        let r = f * f * (0.5 - 0.333_333_333_333_333_3 * f);
+
+        return if k == 0 { f - r } else { dk * ln2_hi - ((r - dk * ln2_lo) - f) };
+    }
+
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let i = (hx - 0x6147A) | (0x6B851 - hx);
+    let t1 = w * (lg2 + w * (lg4 + w * lg6));
+    let t2 = z * (lg1 + w * (lg3 + w * (lg5 + w * lg7)));
+    let r = t2 + t1;
+
+    if i > 0 {
+        let hfsq = 0.5 * f * f;
+
+        if k == 0 {
+            f - (hfsq - s * (hfsq + r))
+        } else {
+            dk * ln2_hi - ((hfsq - (s * (hfsq + r) + dk * ln2_lo)) - f)
+        }
+    } else if k == 0 {
+        f - s * (f - r)
+    } else {
+        dk * ln2_hi - ((s * (f - r) - dk * ln2_lo) - f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // all of the expected values are from OpenJDK 17
+    #[test]
+    fn next_int_test() {
+        let mut random = JavaRandom::new(42);
+
+        let expected: [i32; 4] = [-1170105035, 234785527, -1360544799, 205897768];
+
+        for e in expected.iter() {
+            assert_eq!(random.next_int(), *e);
+        }
+    }
+
+    #[test]
+    fn next_int_bound_test() {
+        let mut random = JavaRandom::new(42);
+
+        for e in [30, 63, 48, 84, 70, 25] {
+            assert_eq!(random.next_int_bound(100), e);
+        }
+
+        let mut random = JavaRandom::new(42);
+
+        for e in [46, 3, 43, 3] {
+            assert_eq!(random.next_int_bound(64), e);
+        }
+
+        // just over a power of two, so about half of the values are rejected
+        let mut random = JavaRandom::new(42);
+
+        for e in [117392763, 102948884, 662969970, 595021505, 196118093, 969067502] {
+            assert_eq!(random.next_int_bound((1 << 30) + 1), e);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_int_bound_zero_test() {
+        JavaRandom::new(42).next_int_bound(0);
+    }
+
+    #[test]
+    fn next_long_test() {
+        let mut random = JavaRandom::new(42);
+
+        let expected: [i64; 4] = [-5025562857975149833, -5843495416241995736, 5694868678511409995, 5111195811822994797];
+
+        for e in expected.iter() {
+            assert_eq!(random.next_long(), *e);
+        }
+    }
+
+    #[test]
+    fn next_double_test() {
+        let mut random = JavaRandom::new(42);
+
+        for e in [4604728530581845079, 4604329149490933249, 4599233015213898676, 4598663022256506966] {
+            assert_eq!(random.next_double().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_float_test() {
+        let mut random = JavaRandom::new(42);
+
+        for e in [1060782493, 1029695648, 1060038587, 1027890176] {
+            assert_eq!(random.next_float().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_boolean_test() {
+        let mut random = JavaRandom::new(42);
+
+        for e in [true, false, true, false, false, true] {
+            assert_eq!(random.next_boolean(), e);
+        }
+    }
+
+    #[test]
+    fn next_gaussian_test() {
+        let mut random = JavaRandom::new(42);
+
+        let expected: [i64; 6] = [
+            4607821503525903750,
+            4606456510138157127,
+            -4616641179245592382,
+            -4615707776640798080,
+            4598733263062401967,
+            4604341753479877564,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(random.next_gaussian().to_bits() as i64, *e);
+        }
+    }
+
+    #[test]
+    fn next_gaussian_hash_test() {
+        // Java's hash of a million gaussians, h = 31 * h + doubleToLongBits(nextGaussian()), so every value matches
+        let mut random = JavaRandom::new(12345);
+        let mut hash: i64 = 0;
+
+        for _ in 0..1_000_000 {
+            hash = hash.wrapping_mul(31).wrapping_add(random.next_gaussian().to_bits() as i64);
+        }
+
+        assert_eq!(hash, -2237937660996347252);
+    }
+
+    #[test]
+    fn set_seed_test() {
+        let mut random = JavaRandom::new(42);
+
+        random.next_gaussian();
+        random.set_seed(1);
+
+        assert_eq!(random.next_gaussian().to_bits(), 4609711554963350721);
+    }
+
+    #[test]
+    fn fill_u32_test() {
+        let mut random = JavaRandom::new(42);
+        let mut expected = JavaRandom::new(42);
+        let mut ns = [0; 8];
+
+        random.fill_u32(&mut ns);
+
+        assert_eq!(ns, [3124862261, 234785527, 2934422497, 205897768, 1325939940, 4046175051, 1190043011, 3039593837]);
+
+        for n in ns.iter() {
+            assert_eq!(*n, expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn next_bytes_test() {
+        let mut random = JavaRandom::new(-7);
+        let mut bytes = [0; 7];
+
+        random.next_bytes(&mut bytes);
+
+        assert_eq!(bytes, [140, 42, 229, 68, 131, 139, 200]);
+    }
+
+    #[test]
+    fn strict_log_test() {
+        assert_eq!(strict_log(1.0), 0.0);
+        assert_eq!(strict_log(0.0), f64::NEG_INFINITY);
+        assert!(strict_log(-1.0).is_nan());
+        assert_eq!(strict_log(std::f64::consts::E), 1.0);
+        assert_eq!(strict_log(f64::MIN_POSITIVE / 4.0), f64::MIN_POSITIVE.ln() - 4f64.ln());
+    }
+}
@@ -0,0 +1,152 @@
+pub mod glibc_rand;
+pub mod java_random;
+pub mod rand48;
+
+mod constants {
+    // Park and Miller's "minimal standard" modulus, 2^31 - 1
+    pub const MINSTD_MODULUS: u64 = 2147483647;
+    pub const MINSTD_MULTIPLIER: u64 = 48271;
+    pub const MINSTD_RAND0_MULTIPLIER: u64 = 16807;
+
+    // shared by the drand48 family and java.util.Random
+    pub const RAND48_MULTIPLIER: u64 = 0x5DEECE66D;
+    pub const RAND48_INCREMENT: u64 = 0xB;
+    pub const RAND48_MODULUS: u64 = 1 << 48;
+}
+
+/// A linear congruential generator, `state = (multiplier * state + increment) % modulus`, with the parameters chosen
+/// at runtime.  A modulus of zero means 2^64.
+/// 
+/// The presets reproduce the sequences of legacy platforms bit for bit, e.g. [`Lcg::minstd`] matches C++'s
+/// `std::minstd_rand`.  See also [`glibc_rand::GlibcRand`], [`java_random::JavaRandom`] and [`rand48::Rand48`],
+/// which add each platform's output functions.
+/// 
+/// Only the state is returned, so this doesn't implement [`crate::RandomNumber`]: most LCGs have weak low bits, and
+/// a modulus that isn't a power of two doesn't give whole random bits at all.
+#[derive(Debug, Clone)]
+pub struct Lcg {
+    multiplier: u64,
+    increment: u64,
+    modulus: u64,
+    state: u64
+}
+
+impl Lcg {
+    /// # Panics
+    /// 
+    /// If `multiplier`, `increment` or `state` isn't less than a non-zero `modulus`.
+    pub fn new(multiplier: u64, increment: u64, modulus: u64, state: u64) -> Lcg {
+        assert!(modulus == 0 || (multiplier < modulus && increment < modulus && state < modulus),
+            "the LCG's parameters and state must be less than its modulus");
+
+        Lcg { multiplier, increment, modulus, state }
+    }
+
+    /// Park and Miller's revised "minimal standard" generator, `state = 48271 * state % (2^31 - 1)`, seeded like
+    /// C++'s `std::minstd_rand`.
+    pub fn minstd(seed: u64) -> Lcg {
+        minstd_from_seed(constants::MINSTD_MULTIPLIER, seed)
+    }
+
+    /// Park and Miller's original "minimal standard" generator, `state = 16807 * state % (2^31 - 1)`, seeded like
+    /// C++'s `std::minstd_rand0`.
+    pub fn minstd_rand0(seed: u64) -> Lcg {
+        minstd_from_seed(constants::MINSTD_RAND0_MULTIPLIER, seed)
+    }
+
+    /// The 48-bit generator used by `drand48` and `java.util.Random`, `state = (0x5DEECE66D * state + 11) % 2^48`.
+    /// Only the low 48 bits of `state` are used.
+    pub fn rand48(state: u64) -> Lcg {
+        Lcg::new(constants::RAND48_MULTIPLIER, constants::RAND48_INCREMENT, constants::RAND48_MODULUS,
+            state & (constants::RAND48_MODULUS - 1))
+    }
+
+    /// Steps the generator and returns the new state, like calling a C++ `std::linear_congruential_engine`.
+    pub fn next_state(&mut self) -> u64 {
+        self.state = if self.modulus == 0 {
+            self.multiplier.wrapping_mul(self.state).wrapping_add(self.increment)
+        } else if self.modulus.is_power_of_two() {
+            self.multiplier.wrapping_mul(self.state).wrapping_add(self.increment) & (self.modulus - 1)
+        } else {
+            ((self.multiplier as u128 * self.state as u128 + self.increment as u128) % self.modulus as u128) as u64
+        };
+
+        self.state
+    }
+
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+}
+
+// the state of a multiplicative generator can't be zero, so C++ uses 1 instead
+fn minstd_from_seed(multiplier: u64, seed: u64) -> Lcg {
+    let state = match seed % constants::MINSTD_MODULUS {
+        0 => 1,
+        state => state,
+    };
+
+    Lcg::new(multiplier, 0, constants::MINSTD_MODULUS, state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minstd_test() {
+        // the C++ standard requires the 10000th output of a default constructed std::minstd_rand to be 399268537
+        let mut lcg = Lcg::minstd(1);
+
+        assert_eq!(lcg.next_state(), 48271);
+
+        for _ in 0..9998 {
+            lcg.next_state();
+        }
+
+        assert_eq!(lcg.next_state(), 399268537);
+    }
+
+    #[test]
+    fn minstd_rand0_test() {
+        // and for std::minstd_rand0, 1043618065
+        let mut lcg = Lcg::minstd_rand0(1);
+
+        assert_eq!(lcg.next_state(), 16807);
+
+        for _ in 0..9998 {
+            lcg.next_state();
+        }
+
+        assert_eq!(lcg.next_state(), 1043618065);
+    }
+
+    #[test]
+    fn minstd_seed_test() {
+        // values from std::minstd_rand
+        let mut lcg = Lcg::minstd(42);
+
+        assert_eq!(lcg.next_state(), 2027382);
+        assert_eq!(lcg.next_state(), 1226992407);
+
+        assert_eq!(Lcg::minstd(0).next_state(), 48271);
+        assert_eq!(Lcg::minstd(constants::MINSTD_MODULUS).next_state(), 48271);
+    }
+
+    #[test]
+    fn modulus_test() {
+        let mut lcg = Lcg::new(6364136223846793005, 1442695040888963407, 0, 1);
+
+        assert_eq!(lcg.next_state(), 6364136223846793005u64.wrapping_add(1442695040888963407));
+
+        let mut lcg = Lcg::new(5, 3, 16, 15);
+
+        assert_eq!(lcg.next_state(), 14);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_invalid_test() {
+        Lcg::new(16, 0, 16, 1);
+    }
+}
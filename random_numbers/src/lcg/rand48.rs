@@ -0,0 +1,141 @@
+use crate::RandomNumber;
+
+use super::Lcg;
+
+mod constants {
+    // srand48() puts the seed in the high 32 bits of the state, and this in the low 16
+    pub const SRAND48_LOW_BITS: u64 = 0x330E;
+
+    pub const STATE_BITS: u32 = 48;
+    pub const TWO_48_INVERSE: f64 = 1.0 / (1u64 << STATE_BITS) as f64;
+}
+
+/// The POSIX `drand48` family, for reproducing results from C programs.  It's the 48-bit LCG from [`Lcg::rand48`],
+/// returning the high bits of the state.
+/// 
+/// [`RandomNumber::next_f64`] returns [`Rand48::drand48`], so it only has 48 bits of precision.
+#[derive(Debug, Clone)]
+pub struct Rand48 {
+    lcg: Lcg
+}
+
+impl Rand48 {
+    /// Seeds the generator like `srand48(seed)`.  Only the low 32 bits of the seed are used.
+    pub fn srand48(seed: i64) -> Rand48 {
+        let state = ((seed as u64 & 0xFFFFFFFF) << 16) | constants::SRAND48_LOW_BITS;
+
+        Rand48 { lcg: Lcg::rand48(state) }
+    }
+
+    /// Seeds the generator like `seed48(seed)`, which sets the whole state with the least significant 16 bits first.
+    pub fn seed48(seed: [u16; 3]) -> Rand48 {
+        let state = (seed[2] as u64) << 32 | (seed[1] as u64) << 16 | seed[0] as u64;
+
+        Rand48 { lcg: Lcg::rand48(state) }
+    }
+
+    /// Returns a number in `[0, 1)`, like `drand48()`.
+    pub fn drand48(&mut self) -> f64 {
+        self.lcg.next_state() as f64 * constants::TWO_48_INVERSE
+    }
+
+    /// Returns a number in `[0, 2^31)`, like `lrand48()`.
+    pub fn lrand48(&mut self) -> i32 {
+        (self.lcg.next_state() >> (constants::STATE_BITS - 31)) as i32
+    }
+
+    /// Returns a number in `[-2^31, 2^31)`, like `mrand48()`.
+    pub fn mrand48(&mut self) -> i32 {
+        (self.lcg.next_state() >> (constants::STATE_BITS - 32)) as u32 as i32
+    }
+}
+
+impl RandomNumber for Rand48 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.mrand48() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.drand48()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // values from glibc 2.36
+    #[test]
+    fn drand48_test() {
+        let mut rand48 = Rand48::srand48(42);
+
+        let expected: [f64; 4] = [0.7445250000610066, 0.342701478718908, 0.11108528244416149, 0.422338957988309];
+
+        for e in expected.iter() {
+            assert_eq!(rand48.drand48(), *e);
+        }
+    }
+
+    #[test]
+    fn lrand48_test() {
+        let mut rand48 = Rand48::srand48(42);
+
+        let expected: [i32; 4] = [1598855263, 735945821, 238553827, 906966006];
+
+        for e in expected.iter() {
+            assert_eq!(rand48.lrand48(), *e);
+        }
+
+        let mut rand48 = Rand48::srand48(-5);
+
+        assert_eq!(rand48.lrand48(), 1754100061);
+        assert_eq!(rand48.lrand48(), 487370682);
+    }
+
+    #[test]
+    fn mrand48_test() {
+        let mut rand48 = Rand48::srand48(42);
+
+        let expected: [i32; 4] = [-1097256770, 1471891643, 477107655, 1813932012];
+
+        for e in expected.iter() {
+            assert_eq!(rand48.mrand48(), *e);
+        }
+    }
+
+    #[test]
+    fn seed48_test() {
+        let mut rand48 = Rand48::seed48([0x1234, 0x5678, 0x9ABC]);
+
+        assert_eq!(rand48.drand48(), 0.286599243634722);
+        assert_eq!(rand48.drand48(), 0.9343890928194512);
+    }
+
+    #[test]
+    fn random_number_test() {
+        let mut rand48 = Rand48::srand48(42);
+
+        assert_eq!(rand48.next_u32(), -1097256770i32 as u32);
+        assert_eq!(rand48.next_f64(), 0.342701478718908);
+    }
+}
@@ -38,6 +38,20 @@
 //! let mut rand_mt = random_numbers::from_seed(RandomNumberAlgorithm::MersenneTwister, 0);
 //! ```
 //! 
+//! If you need several streams of random numbers that never overlap, e.g. one per thread, use a
+//! generator implementing [`RandomNumberJump`]:
+//! 
+//! ```
+//! use random_numbers::prelude::*;
+//! 
+//! let mut rand = Xoshiro256SS::from_seed(0);
+//! 
+//! let streams = rand.jump_streams(4);
+//! ```
+//! 
+//! or, when the number of streams isn't known in advance, [`RandomNumberSplit`].  To start at an
+//! arbitrary position in a stream use [`RandomNumberAdvance`].
+//! 
 //! This is a low-level library and each numerical type (plus `bool`) has its own method.
 
 /// A convenient way to import all the main functionality of the library.
@@ -54,18 +68,70 @@ pub mod prelude {
     pub use crate::RandomNumber;
 }
 
+mod chacha;
+mod iter;
+mod jsf;
+mod lcg;
+mod mcg;
 mod mersenne_twister;
 mod msws;
+mod pcg;
+#[cfg(feature = "rand_core")]
+mod rand_core_compat;
+mod random123;
+mod romu;
 mod seeds;
+mod sfc;
 mod split_mix;
+mod state;
+mod well;
+mod wyrand;
 mod xorshift;
 
+use std::error::Error;
+use std::fmt;
 use std::fmt::Debug;
+use std::io;
 
-use mersenne_twister::MersenneTwister;
-use msws::MiddleSquaresWeylSequence;
-use xorshift::xorshift_plus::XorshiftPlus;
-use xorshift::xoshiro_256ss::Xoshiro256SS;
+pub use chacha::{ChaCha, ChaCha8, ChaCha12, ChaCha20, ChaChaRounds, ValidRounds};
+pub use iter::RandomIter;
+pub use jsf::Jsf64;
+pub use lcg::Lcg;
+pub use lcg::glibc_rand::GlibcRand;
+pub use lcg::java_random::JavaRandom;
+pub use lcg::rand48::Rand48;
+pub use mcg::lehmer64::Lehmer64;
+pub use mcg::mcg128::Mcg128;
+pub use mersenne_twister::MersenneTwister;
+pub use mersenne_twister::legacy::MersenneTwisterLegacy;
+pub use mersenne_twister::mt19937::MT19937;
+pub use mersenne_twister::sfmt::Sfmt19937;
+pub use msws::MiddleSquaresWeylSequence;
+pub use msws::squares::{Squares32, Squares64};
+pub use pcg::pcg32::Pcg32;
+pub use pcg::pcg64::Pcg64;
+pub use pcg::pcg64_dxsm::Pcg64Dxsm;
+#[cfg(feature = "rand_core")]
+pub use rand_core_compat::{LargeSeed, RngCoreAdapter};
+pub use random123::philox::Philox4x32;
+pub use random123::threefry::Threefry4x64;
+pub use romu::romu_duo_jr::RomuDuoJr;
+pub use romu::romu_trio::RomuTrio;
+pub use sfc::Sfc64;
+pub use split_mix::SplitMix;
+pub use well::well1024a::Well1024a;
+pub use well::well512a::Well512a;
+pub use wyrand::WyRand;
+pub use xorshift::xoroshiro_128p::Xoroshiro128P;
+pub use xorshift::xoroshiro_128pp::Xoroshiro128PP;
+pub use xorshift::xoroshiro_128ss::Xoroshiro128SS;
+pub use xorshift::xorshift_plus::XorshiftPlus;
+pub use xorshift::xoshiro_128pp::Xoshiro128PP;
+pub use xorshift::xoshiro_128ss::Xoshiro128SS;
+pub use xorshift::xoshiro_256p::Xoshiro256P;
+pub use xorshift::xoshiro_256pp::Xoshiro256PP;
+pub use xorshift::xoshiro_256ss::Xoshiro256SS;
+pub use xorshift::xoshiro_512ss::Xoshiro512SS;
 
 /// 2^31. Primarily used to convert a random u32 into a bool. We compare the random u32 to 2^31 instead
 /// of comparing it to 1 mod 2 because the highest bit is usually of better quality than the lowest bit.
@@ -83,21 +149,190 @@ pub const TWO_23_INVERSE: f32 = 1.0 / TWO_23;
 pub const TWO_53: f64 = 0b00000000_00100000_00000000_00000000_00000000_00000000_00000000_00000000u64 as f64;
 pub const TWO_53_INVERSE: f64 = 1.0 / TWO_53;
 
+mod constants {
+    // the exponents of the smallest normal floats, and below which a value rounds down to 0
+    pub const F32_MIN_NORMAL_EXPONENT: i32 = -126;
+    pub const F32_MIN_EXPONENT: i32 = -150;
+    pub const F64_MIN_NORMAL_EXPONENT: i32 = -1022;
+    pub const F64_MIN_EXPONENT: i32 = -1075;
+}
+
 /// Specifies how a random number generator should be able to be initialized.
-pub trait RandomNumberInit {
-    /// Creates a new random number generator, using the current time in nanos as its seed.
+pub trait RandomNumberInit: Sized {
+    /// The number of bytes [`RandomNumberInit::from_seed_bytes`] expects, which is enough to set the
+    /// generator's entire state.
+    const SEED_BYTES: usize;
+
+    /// Creates a new random number generator, with its entire state filled with random bytes from
+    /// the operating system (`/dev/urandom`).
     /// 
     /// This should be sufficient in most situations where you don't need/want repeatable
     /// sequences of random numbers.
     /// 
-    /// What it means to be a "seed" depends on the algorithm chosen.
-    fn new() -> Self;
+    /// # Panics
+    /// 
+    /// If the operating system can't supply random bytes, which is always the case on targets other
+    /// than unix.  Use [`RandomNumberInit::try_new`] to handle that case, e.g. by falling back to
+    /// [`RandomNumberInit::from_time`].
+    fn new() -> Self {
+        Self::try_new().expect("unable to read random bytes from the operating system")
+    }
+
+    /// Creates a new random number generator, with its entire state filled with random bytes from
+    /// the operating system, or returns the error encountered reading them.
+    fn try_new() -> io::Result<Self>;
 
     /// Supply your own seed to the random number generator.  The same seed will always
     /// produce the same sequence of random numbers.  Useful if you want repeatable tests.
     /// 
     /// What it means to be a "seed" depends on the algorithm chosen.
     fn from_seed(seed: u64) -> Self;
+
+    /// Sets the generator's entire state directly from `bytes`, read as little endian words.  Unlike
+    /// [`RandomNumberInit::from_seed`], every possible state of the generator can be reached.
+    /// 
+    /// Returns an error if `bytes` isn't exactly [`RandomNumberInit::SEED_BYTES`] long, or if it would
+    /// leave the generator in a state it can't escape from (e.g. all zeros for the xorshift family).
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Self, SeedError>;
+
+    /// Creates a new random number generator seeded with the current time.  Only use it as a fallback
+    /// when [`RandomNumberInit::try_new`] fails: at most 64 bits of the state come from the seed, and
+    /// the time is far easier to guess than random bytes from the operating system.
+    fn from_time() -> Self {
+        Self::from_seed(seeds::current_nanos())
+    }
+}
+
+/// The reasons [`RandomNumberInit::from_seed_bytes`] can reject a seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedError {
+    /// The seed doesn't have the number of bytes the generator expects.
+    WrongLength { expected: usize, actual: usize },
+    /// The seed is all zeros, which the generator would never leave.
+    AllZero,
+    /// The seed is even, but the generator's state must be odd.
+    Even,
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedError::WrongLength { expected, actual } => write!(f, "expected a seed of {} bytes, got {}", expected, actual),
+            SeedError::AllZero => write!(f, "the generator can't be seeded with all zeros"),
+            SeedError::Even => write!(f, "the generator can't be seeded with an even number"),
+        }
+    }
+}
+
+impl Error for SeedError {}
+
+/// Saves and restores a generator's complete state, e.g. to checkpoint a long simulation and later resume
+/// it with the exact same sequence of random numbers.
+/// 
+/// The format is stable and versioned: the magic bytes `RNGS`, a format version byte, a byte identifying
+/// the algorithm, then the generator's state as little endian words.  States saved by this version of
+/// the library can be restored by every later version.  Use [`restore_state`] to restore a state without
+/// knowing the algorithm in advance.
+/// 
+/// ```
+/// use random_numbers::prelude::*;
+/// 
+/// let mut rand = Xoshiro256SS::from_seed(0);
+/// let state = rand.save_state();
+/// let first = rand.next_u64();
+/// 
+/// let mut restored = Xoshiro256SS::restore_state(&state).unwrap();
+/// assert_eq!(restored.next_u64(), first);
+/// ```
+pub trait RandomNumberState: Sized {
+    /// Returns the generator's complete state.
+    fn save_state(&self) -> Vec<u8>;
+
+    /// Creates a generator from a state returned by [`RandomNumberState::save_state`].  Returns an
+    /// error if the state is malformed, or was saved by a different algorithm.
+    fn restore_state(state: &[u8]) -> Result<Self, StateError>;
+}
+
+/// The reasons a saved state can't be restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateError {
+    /// The bytes don't start with a state header.
+    NotAState,
+    /// The state was saved in a format version this version of the library doesn't know about.
+    UnsupportedVersion(u8),
+    /// The state was saved by an algorithm this version of the library doesn't know about.
+    UnknownAlgorithm(u8),
+    /// The state was saved by another algorithm, identified by the given tag.
+    WrongAlgorithm(u8),
+    /// The state is too short or too long for its algorithm.
+    WrongLength,
+    /// The state holds values the generator can never be in.
+    InvalidState,
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateError::NotAState => write!(f, "not a saved random number generator state"),
+            StateError::UnsupportedVersion(version) => write!(f, "unsupported state format version {}", version),
+            StateError::UnknownAlgorithm(tag) => write!(f, "unknown algorithm {} in saved state", tag),
+            StateError::WrongAlgorithm(tag) => write!(f, "the state was saved by another algorithm ({})", tag),
+            StateError::WrongLength => write!(f, "the state has the wrong length for its algorithm"),
+            StateError::InvalidState => write!(f, "the state holds values the generator can never be in"),
+        }
+    }
+}
+
+impl Error for StateError {}
+
+// macro to implement the exclusive and inclusive range functions, like `next_u8_range`.  `$unsigned` is the
+// unsigned type of the same width as `$range_type`, and `$below` draws values of type `$wide`, which is at least
+// 32 bits wide so the narrow types share the u32 implementation.
+macro_rules! make_next_range {
+    ($range_name: ident, $inclusive_name: ident, $range_type: ty, $unsigned: ty, $wide: ty, $below: ident, $next_wide: ident) => {
+        #[doc = concat!("Returns a random [`", stringify!($range_type), "`] in `[min, max)`, without bias.")]
+        ///
+        /// # Panics
+        ///
+        /// If `min >= max`.
+        #[inline]
+        fn $range_name(&mut self, min: $range_type, max: $range_type) -> $range_type {
+            assert!(min < max, "the range must not be empty");
+
+            let span = max.wrapping_sub(min) as $unsigned as $wide;
+
+            min.wrapping_add(self.$below(span) as $range_type)
+        }
+
+        #[doc = concat!("Returns a random [`", stringify!($range_type), "`] in `[min, max]`, without bias.")]
+        ///
+        /// # Panics
+        ///
+        /// If `min > max`.
+        #[inline]
+        fn $inclusive_name(&mut self, min: $range_type, max: $range_type) -> $range_type {
+            assert!(min <= max, "the range must not be empty");
+
+            let span = (max.wrapping_sub(min) as $unsigned as $wide).wrapping_add(1);
+
+            // the span only wraps around for the type's entire range, where every value is fair game
+            if span == 0 {
+                return self.$next_wide() as $range_type;
+            }
+
+            min.wrapping_add(self.$below(span) as $range_type)
+        }
+    };
+}
+
+macro_rules! make_iter {
+    ($iter_name: ident, $next_name: ident, $next_type: ty) => {
+        #[doc = concat!("Returns an infinite iterator of random [`", stringify!($next_type), "`]s, the same sequence as calling [`RandomNumber::", stringify!($next_name), "`] repeatedly.")]
+        #[inline]
+        fn $iter_name(&mut self) -> RandomIter<'_, Self, $next_type> where Self: Sized {
+            RandomIter::new(self, Self::$next_name)
+        }
+    };
 }
 
 /// Specifies all the types a random number generator implementation should be able to provide.
@@ -182,26 +417,477 @@ pub trait RandomNumber: Debug {
         ((self.next_u64() >> 11) as f64) * TWO_53_INVERSE
     }
 
+    /// Returns a random [`f32`] in the open interval `(0, 1)`, for samplers that take the log of the
+    /// result.  The values are odd multiples of 2^-23, so they're symmetric around 1/2.
+    #[inline]
+    fn next_f32_open(&mut self) -> f32 {
+        (((self.next_u32() >> 9) | 1) as f32) * TWO_23_INVERSE
+    }
+
+    /// Returns a random [`f64`] in the open interval `(0, 1)`, for samplers that take the log of the
+    /// result.  The values are odd multiples of 2^-53, so they're symmetric around 1/2.
+    #[inline]
+    fn next_f64_open(&mut self) -> f64 {
+        (((self.next_u64() >> 11) | 1) as f64) * TWO_53_INVERSE
+    }
+
+    /// Returns a random [`f32`] in the closed interval `[0, 1]`.
+    #[inline]
+    fn next_f32_closed(&mut self) -> f32 {
+        ((self.next_u32() >> 9) as f32) / (TWO_23 - 1.0)
+    }
+
+    /// Returns a random [`f64`] in the closed interval `[0, 1]`.
+    #[inline]
+    fn next_f64_closed(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64) / (TWO_53 - 1.0)
+    }
+
+    /// Returns a random [`f32`] in the half-open interval `(0, 1]`, i.e. `1 - next_f32()`.
+    #[inline]
+    fn next_f32_open_closed(&mut self) -> f32 {
+        (((self.next_u32() >> 9) + 1) as f32) * TWO_23_INVERSE
+    }
+
+    /// Returns a random [`f64`] in the half-open interval `(0, 1]`, i.e. `1 - next_f64()`.
+    #[inline]
+    fn next_f64_open_closed(&mut self) -> f64 {
+        (((self.next_u64() >> 11) + 1) as f64) * TWO_53_INVERSE
+    }
+
+    /// Returns a random [`f32`] in `[0, 1)` that can be any representable value, not just a multiple of
+    /// 2^-23, with probability proportional to the gap up to the next [`f32`].  See Allen Downey's
+    /// [Generating Pseudo-random Floating-Point Values](https://allendowney.com/research/rand/).
+    ///
+    /// Small values get the full 24 bits of precision, at the cost of a second draw.
+    fn next_f32_full_precision(&mut self) -> f32 {
+        // each leading zero halves the value, so the exponent is geometrically distributed
+        let mut exponent = -1;
+        let mut bits = self.next_u32();
+
+        while bits == 0 {
+            exponent -= 32;
+
+            if exponent < constants::F32_MIN_EXPONENT {
+                return 0.0;
+            }
+
+            bits = self.next_u32();
+        }
+
+        exponent -= bits.leading_zeros() as i32;
+        let mantissa = self.next_u32() >> 9;
+
+        if exponent < constants::F32_MIN_NORMAL_EXPONENT {
+            // subnormal, truncated to a multiple of the smallest positive f32
+            let shift = (constants::F32_MIN_NORMAL_EXPONENT - exponent) as u32;
+            return f32::from_bits((mantissa | 1 << 23).checked_shr(shift).unwrap_or(0));
+        }
+
+        f32::from_bits(((exponent + 127) as u32) << 23 | mantissa)
+    }
+
+    /// Returns a random [`f64`] in `[0, 1)` that can be any representable value, not just a multiple of
+    /// 2^-53, with probability proportional to the gap up to the next [`f64`].  See
+    /// [`RandomNumber::next_f32_full_precision`].
+    fn next_f64_full_precision(&mut self) -> f64 {
+        let mut exponent = -1;
+        let mut bits = self.next_u64();
+
+        while bits == 0 {
+            exponent -= 64;
+
+            if exponent < constants::F64_MIN_EXPONENT {
+                return 0.0;
+            }
+
+            bits = self.next_u64();
+        }
+
+        exponent -= bits.leading_zeros() as i32;
+        let mantissa = self.next_u64() >> 12;
+
+        if exponent < constants::F64_MIN_NORMAL_EXPONENT {
+            let shift = (constants::F64_MIN_NORMAL_EXPONENT - exponent) as u32;
+            return f64::from_bits((mantissa | 1 << 52).checked_shr(shift).unwrap_or(0));
+        }
+
+        f64::from_bits(((exponent + 1023) as u64) << 52 | mantissa)
+    }
+
     /// Returns a random [`usize`] on 16-bit architectures
     #[inline]
-    #[cfg(any(target_pointer_width = "16"))]
+    #[cfg(target_pointer_width = "16")]
     fn next_usize(&mut self) -> usize {
         self.next_u16() as usize
     }
 
     /// Returns a random [`usize`] on 32-bit architectures
     #[inline]
-    #[cfg(any(target_pointer_width = "32"))]
+    #[cfg(target_pointer_width = "32")]
     fn next_usize(&mut self) -> usize {
         self.next_u32() as usize
     }
 
     /// Returns a random [`usize`] on 64-bit architectures
     #[inline]
-    #[cfg(any(target_pointer_width = "64"))]
+    #[cfg(target_pointer_width = "64")]
     fn next_usize(&mut self) -> usize {
         self.next_u64() as usize
     }
+
+    /// Returns a random [`u32`] in `[0, n)`, without bias.  Uses Lemire's nearly divisionless method, see
+    /// [Fast Random Integer Generation in an Interval](https://arxiv.org/abs/1805.10941).
+    /// 
+    /// # Panics
+    /// 
+    /// If `n` is 0.
+    #[inline]
+    fn next_u32_below(&mut self, n: u32) -> u32 {
+        assert!(n > 0, "the bound must be greater than 0");
+
+        let mut m = (self.next_u32() as u64) * (n as u64);
+
+        if (m as u32) < n {
+            // 2^32 mod n, the number of low values that would otherwise be picked once too often
+            let threshold = n.wrapping_neg() % n;
+
+            while (m as u32) < threshold {
+                m = (self.next_u32() as u64) * (n as u64);
+            }
+        }
+
+        (m >> 32) as u32
+    }
+
+    /// Returns a random [`u64`] in `[0, n)`, without bias.  See [`RandomNumber::next_u32_below`].
+    /// 
+    /// # Panics
+    /// 
+    /// If `n` is 0.
+    #[inline]
+    fn next_u64_below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "the bound must be greater than 0");
+
+        let mut m = (self.next_u64() as u128) * (n as u128);
+
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+
+            while (m as u64) < threshold {
+                m = (self.next_u64() as u128) * (n as u128);
+            }
+        }
+
+        (m >> 64) as u64
+    }
+
+    /// Returns a random [`u128`] in `[0, n)`, without bias.  See [`RandomNumber::next_u32_below`].
+    /// 
+    /// # Panics
+    /// 
+    /// If `n` is 0.
+    #[inline]
+    fn next_u128_below(&mut self, n: u128) -> u128 {
+        assert!(n > 0, "the bound must be greater than 0");
+
+        let (mut high, mut low) = widening_mul_u128(self.next_u128(), n);
+
+        if low < n {
+            let threshold = n.wrapping_neg() % n;
+
+            while low < threshold {
+                let (h, l) = widening_mul_u128(self.next_u128(), n);
+                high = h;
+                low = l;
+            }
+        }
+
+        high
+    }
+
+    /// Returns a random [`usize`] in `[0, n)`, without bias.  Useful for picking an index.
+    /// 
+    /// # Panics
+    /// 
+    /// If `n` is 0.
+    #[inline]
+    fn next_usize_below(&mut self, n: usize) -> usize {
+        self.next_u64_below(n as u64) as usize
+    }
+
+    make_next_range!(next_u8_range, next_u8_range_inclusive, u8, u8, u32, next_u32_below, next_u32);
+    make_next_range!(next_i8_range, next_i8_range_inclusive, i8, u8, u32, next_u32_below, next_u32);
+    make_next_range!(next_u16_range, next_u16_range_inclusive, u16, u16, u32, next_u32_below, next_u32);
+    make_next_range!(next_i16_range, next_i16_range_inclusive, i16, u16, u32, next_u32_below, next_u32);
+    make_next_range!(next_u32_range, next_u32_range_inclusive, u32, u32, u32, next_u32_below, next_u32);
+    make_next_range!(next_i32_range, next_i32_range_inclusive, i32, u32, u32, next_u32_below, next_u32);
+    make_next_range!(next_u64_range, next_u64_range_inclusive, u64, u64, u64, next_u64_below, next_u64);
+    make_next_range!(next_i64_range, next_i64_range_inclusive, i64, u64, u64, next_u64_below, next_u64);
+    make_next_range!(next_u128_range, next_u128_range_inclusive, u128, u128, u128, next_u128_below, next_u128);
+    make_next_range!(next_i128_range, next_i128_range_inclusive, i128, u128, u128, next_u128_below, next_u128);
+    make_next_range!(next_usize_range, next_usize_range_inclusive, usize, usize, u64, next_u64_below, next_u64);
+    make_next_range!(next_isize_range, next_isize_range_inclusive, isize, usize, u64, next_u64_below, next_u64);
+
+    /// Fills `dest` with random bytes, taken from [`RandomNumber::next_u64`] in little endian order.
+    /// One call through a `Box<dyn RandomNumber>` fills the whole buffer, rather than one call per value.
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+
+        let remainder = chunks.into_remainder();
+
+        if !remainder.is_empty() {
+            let len = remainder.len();
+            remainder.copy_from_slice(&self.next_u64().to_le_bytes()[..len]);
+        }
+    }
+
+    /// Fills `dest` with random [`u32`]s, splitting each [`RandomNumber::next_u64`] into its high and then
+    /// its low half.  For generators that build `next_u64` out of two 32-bit outputs, high first, this is the
+    /// same sequence as calling [`RandomNumber::next_u32`] repeatedly.
+    #[inline]
+    fn fill_u32(&mut self, dest: &mut [u32]) {
+        let mut chunks = dest.chunks_exact_mut(2);
+
+        for chunk in &mut chunks {
+            let n = self.next_u64();
+            chunk[0] = (n >> 32) as u32;
+            chunk[1] = n as u32;
+        }
+
+        for n in chunks.into_remainder() {
+            *n = self.next_u32();
+        }
+    }
+
+    /// Fills `dest` with random [`u64`]s, the same sequence as calling [`RandomNumber::next_u64`] repeatedly.
+    #[inline]
+    fn fill_u64(&mut self, dest: &mut [u64]) {
+        for n in dest {
+            *n = self.next_u64();
+        }
+    }
+
+    /// Fills `dest` with random [`f64`]s in `[0, 1)`, the same sequence as calling
+    /// [`RandomNumber::next_f64`] repeatedly.
+    #[inline]
+    fn fill_f64(&mut self, dest: &mut [f64]) {
+        for n in dest {
+            *n = self.next_f64();
+        }
+    }
+
+    make_iter!(iter_bool, next_bool, bool);
+    make_iter!(iter_u8, next_u8, u8);
+    make_iter!(iter_i8, next_i8, i8);
+    make_iter!(iter_u16, next_u16, u16);
+    make_iter!(iter_i16, next_i16, i16);
+    make_iter!(iter_u32, next_u32, u32);
+    make_iter!(iter_i32, next_i32, i32);
+    make_iter!(iter_u64, next_u64, u64);
+    make_iter!(iter_i64, next_i64, i64);
+    make_iter!(iter_u128, next_u128, u128);
+    make_iter!(iter_i128, next_i128, i128);
+    make_iter!(iter_f32, next_f32, f32);
+    make_iter!(iter_f64, next_f64, f64);
+
+    /// Returns the generator's complete state in the format read by [`restore_state`], or `None` if
+    /// the generator can't be saved.  Unlike [`RandomNumberState::save_state`], this also works for a
+    /// `Box<dyn RandomNumber>`.
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+// multiplies two u128s, returning the high and low 128 bits of the 256-bit product
+#[inline]
+fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const LOW_64: u128 = u64::MAX as u128;
+
+    let (a_high, a_low) = (a >> 64, a & LOW_64);
+    let (b_high, b_low) = (b >> 64, b & LOW_64);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+
+    // at most 3 * (2^64 - 1), so this can't overflow
+    let middle = (low_low >> 64) + (high_low & LOW_64) + (low_high & LOW_64);
+
+    let low = (low_low & LOW_64) | (middle << 64);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+// forwards every method, so a wrapped generator keeps its own overrides (e.g. MT19937's next_f64)
+macro_rules! forward_random_number {
+    ($($name: ident($($arg: ident: $arg_type: ty),*) -> $return_type: ty;)*) => {
+        $(
+            #[inline]
+            fn $name(&mut self, $($arg: $arg_type),*) -> $return_type {
+                (**self).$name($($arg),*)
+            }
+        )*
+
+        fn snapshot(&self) -> Option<Vec<u8>> {
+            (**self).snapshot()
+        }
+    };
+}
+
+macro_rules! impl_forward_random_number {
+    ($generator: ty) => {
+        impl<R: RandomNumber + ?Sized> RandomNumber for $generator {
+            forward_random_number!(
+                next_bool() -> bool;
+                next_u8() -> u8;
+                next_i8() -> i8;
+                next_u16() -> u16;
+                next_i16() -> i16;
+                next_u32() -> u32;
+                next_i32() -> i32;
+                next_u64() -> u64;
+                next_i64() -> i64;
+                next_u128() -> u128;
+                next_i128() -> i128;
+                next_f32() -> f32;
+                next_f64() -> f64;
+                next_f32_open() -> f32;
+                next_f64_open() -> f64;
+                next_f32_closed() -> f32;
+                next_f64_closed() -> f64;
+                next_f32_open_closed() -> f32;
+                next_f64_open_closed() -> f64;
+                next_f32_full_precision() -> f32;
+                next_f64_full_precision() -> f64;
+                next_usize() -> usize;
+                next_u32_below(n: u32) -> u32;
+                next_u64_below(n: u64) -> u64;
+                next_u128_below(n: u128) -> u128;
+                next_usize_below(n: usize) -> usize;
+                next_u8_range(min: u8, max: u8) -> u8;
+                next_u8_range_inclusive(min: u8, max: u8) -> u8;
+                next_i8_range(min: i8, max: i8) -> i8;
+                next_i8_range_inclusive(min: i8, max: i8) -> i8;
+                next_u16_range(min: u16, max: u16) -> u16;
+                next_u16_range_inclusive(min: u16, max: u16) -> u16;
+                next_i16_range(min: i16, max: i16) -> i16;
+                next_i16_range_inclusive(min: i16, max: i16) -> i16;
+                next_u32_range(min: u32, max: u32) -> u32;
+                next_u32_range_inclusive(min: u32, max: u32) -> u32;
+                next_i32_range(min: i32, max: i32) -> i32;
+                next_i32_range_inclusive(min: i32, max: i32) -> i32;
+                next_u64_range(min: u64, max: u64) -> u64;
+                next_u64_range_inclusive(min: u64, max: u64) -> u64;
+                next_i64_range(min: i64, max: i64) -> i64;
+                next_i64_range_inclusive(min: i64, max: i64) -> i64;
+                next_u128_range(min: u128, max: u128) -> u128;
+                next_u128_range_inclusive(min: u128, max: u128) -> u128;
+                next_i128_range(min: i128, max: i128) -> i128;
+                next_i128_range_inclusive(min: i128, max: i128) -> i128;
+                next_usize_range(min: usize, max: usize) -> usize;
+                next_usize_range_inclusive(min: usize, max: usize) -> usize;
+                next_isize_range(min: isize, max: isize) -> isize;
+                next_isize_range_inclusive(min: isize, max: isize) -> isize;
+                fill_bytes(dest: &mut [u8]) -> ();
+                fill_u32(dest: &mut [u32]) -> ();
+                fill_u64(dest: &mut [u64]) -> ();
+                fill_f64(dest: &mut [f64]) -> ();
+            );
+        }
+    };
+}
+
+// lets a boxed generator, e.g. from `new`, or a borrowed one be used wherever a generic `R: RandomNumber` is expected
+impl_forward_random_number!(Box<R>);
+impl_forward_random_number!(&mut R);
+
+/// Marker trait for random number generators that are cryptographically secure, i.e. suitable for
+/// generating tokens, nonces and keys.  Use it as a bound to require a secure source at compile time:
+/// 
+/// ```
+/// use random_numbers::prelude::*;
+/// 
+/// fn new_token<R: CryptoRandomNumber>(rand: &mut R) -> u128 {
+///     rand.next_u128()
+/// }
+/// 
+/// let token = new_token(&mut ChaCha20::new());
+/// ```
+/// 
+/// None of the other generators in this library implement it.
+pub trait CryptoRandomNumber: RandomNumber {}
+
+/// Random number generators that can cheaply skip ahead a very large, fixed number of steps.
+/// 
+/// Jumping is the recommended way to create non-overlapping substreams from a single seed, e.g.
+/// one generator per thread in a parallel simulation.  The size of a jump depends on the algorithm
+/// chosen, but is always large enough that the substreams will never overlap in practice.
+pub trait RandomNumberJump: RandomNumber + Clone {
+    /// Advances the generator as if [`RandomNumber::next_u64`] had been called a fixed number of times.
+    fn jump(&mut self);
+
+    /// Advances the generator by a larger fixed amount than [`RandomNumberJump::jump`].  Use it to
+    /// create substreams which can themselves be split by calling [`RandomNumberJump::jump`].
+    fn long_jump(&mut self);
+
+    /// Returns `n` generators whose streams don't overlap, each one [`RandomNumberJump::jump`] apart.
+    /// The first generator continues the current stream, and `self` is left positioned after the
+    /// last one, so it doesn't overlap with any of them either.
+    fn jump_streams(&mut self, n: usize) -> Vec<Self> {
+        (0..n).map(|_| {
+            let stream = self.clone();
+            self.jump();
+            stream
+        }).collect()
+    }
+}
+
+/// Random number generators that can skip ahead by an arbitrary number of steps without generating
+/// the numbers in between.
+/// 
+/// This makes it cheap to start each worker of a parallel simulation at a known position in a single
+/// stream, e.g. worker `k` at step `k * 1_000_000_000`.  Counter-based generators such as SplitMix
+/// advance in O(1), and linear congruential generators such as PCG in O(log `delta`).
+/// 
+/// ```
+/// use random_numbers::prelude::*;
+/// 
+/// let mut worker = Pcg64::from_seed(0);
+/// worker.advance(3 * 1_000_000_000);
+/// ```
+pub trait RandomNumberAdvance: RandomNumber {
+    /// Advances the generator as if it had stepped `delta` times.  A step produces one
+    /// [`RandomNumber::next_u64`], except for generators with 32 bits of output, e.g.
+    /// [`Pcg32`], where it produces one [`RandomNumber::next_u32`].  `delta` wraps around generators with
+    /// a shorter period.
+    fn advance(&mut self, delta: u128);
+}
+
+/// Random number generators that can split off a new, statistically independent generator.
+/// 
+/// Unlike [`RandomNumberJump`], splitting doesn't need to know how many streams are wanted up
+/// front: each task in a recursive fork-join computation can split its own generator before
+/// forking, and every child is still determined by the original seed.
+/// 
+/// ```
+/// use random_numbers::prelude::*;
+/// 
+/// let mut rand = SplitMix::from_seed(0);
+/// let mut child = rand.split();
+/// 
+/// let n = child.next_u64();
+/// ```
+pub trait RandomNumberSplit: RandomNumber + Sized {
+    /// Returns a new generator, advancing `self` past the values used to create it.
+    fn split(&mut self) -> Self;
 }
 
 /// List of implemented Random Number Generators available for selection.
@@ -210,34 +896,174 @@ pub trait RandomNumber: Debug {
 /// 
 /// See [Wikipedia PRGN List](https://en.wikipedia.org/wiki/List_of_random_number_generators)
 /// for a list of possible additions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RandomNumberAlgorithm {
+    /// See [the ChaCha Wikipedia page](https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant).
+    /// Cryptographically secure, with 8 rounds.
+    ChaCha8,
+    /// See [the ChaCha Wikipedia page](https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant).
+    /// Cryptographically secure, with 12 rounds.
+    ChaCha12,
+    /// See [the ChaCha Wikipedia page](https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant).
+    /// Cryptographically secure, with 20 rounds.
+    ChaCha20,
+    /// See [A small noncryptographic PRNG](https://burtleburtle.net/bob/rand/smallprng.html) (Bob Jenkins).
+    /// 256 bits of state and no multiplications.
+    Jsf64,
+    /// See [The fastest conventional random number generator that can pass Big Crush?](https://lemire.me/blog/2019/03/19/the-fastest-conventionally-random-number-generator-that-can-pass-big-crush/)
+    /// (Daniel Lemire).  128 bits of state and one multiplication per output.
+    Lehmer64,
+    /// Lehmer64 with PCG64's 128-bit multiplier.  [`Mcg128::with_multiplier`] chooses another.
+    Mcg128,
     /// See [the Mersenne Twister Wikipedia page](https://en.wikipedia.org/wiki/Mersenne_Twister).
+    /// Matches the reference MT19937-64 implementation, and C++'s `std::mt19937_64`.
     MersenneTwister,
+    /// The Mersenne Twister as implemented in version 0.1.0 of this library, which doesn't match
+    /// the reference implementation.  Only use it to reproduce sequences from that version.
+    MersenneTwisterLegacy,
+    /// See [the Mersenne Twister Wikipedia page](https://en.wikipedia.org/wiki/Mersenne_Twister).
+    /// The 32-bit version, matching C++'s `std::mt19937` and Python's `random` module.
+    MT19937,
     /// See [the Middle Square Weyl Sequence Wikipedia page](https://en.wikipedia.org/wiki/Middle-square_method#Middle_Square_Weyl_Sequence_PRNG).
     MiddleSquareWeylSequence,
+    /// See [the Permuted Congruential Generator Wikipedia page](https://en.wikipedia.org/wiki/Permuted_congruential_generator).
+    /// 64 bits of state, 32-bit output (PCG-XSH-RR).
+    Pcg32,
+    /// See [the Permuted Congruential Generator Wikipedia page](https://en.wikipedia.org/wiki/Permuted_congruential_generator).
+    /// 128 bits of state, 64-bit output (PCG-XSL-RR).
+    Pcg64,
+    /// See [the Permuted Congruential Generator Wikipedia page](https://en.wikipedia.org/wiki/Permuted_congruential_generator).
+    /// 128 bits of state, 64-bit output, using the DXSM output function.  This is NumPy's default generator.
+    Pcg64Dxsm,
+    /// See [Random123](https://github.com/DEShawResearch/random123).  Counter-based with 10 rounds and
+    /// 32 bits of output, so any block can be computed directly with [`Philox4x32::block`].
+    Philox4x32,
+    /// See [romu-random.org](https://www.romu-random.org/).  128 bits of state, the fastest Romu
+    /// generator, for runs of up to 2^51 bytes.
+    RomuDuoJr,
+    /// See [romu-random.org](https://www.romu-random.org/).  192 bits of state, for runs of up to
+    /// 2^75 bytes.
+    RomuTrio,
+    /// See [PractRand](https://pracrand.sourceforge.net/).  Chris Doty-Humphrey's small fast chaotic
+    /// generator, with 256 bits of state including a counter.
+    Sfc64,
+    /// See [the SFMT page](https://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/SFMT/) (Saito and Matsumoto).
+    /// The SIMD-oriented Mersenne Twister, matching the reference SFMT19937 with 32 bits of output.
+    Sfmt19937,
+    /// See [Fast Splittable Pseudorandom Number Generators](https://doi.org/10.1145/2714064.2660195).
+    /// 64 bits of state; [`RandomNumberSplit::split`] derives independent generators for fork-join work.
+    SplitMix64,
+    /// See [Squares: A Fast Counter-Based RNG](https://arxiv.org/abs/2004.06278).  Counter-based with 32 bits
+    /// of output, so any position can be computed directly with [`Squares32::at`].
+    Squares32,
+    /// See [Squares: A Fast Counter-Based RNG](https://arxiv.org/abs/2004.06278).  Counter-based with 64 bits
+    /// of output, so any position can be computed directly with [`Squares64::at`].
+    Squares64,
+    /// See [Random123](https://github.com/DEShawResearch/random123).  Counter-based with 20 rounds and
+    /// 64 bits of output, so any block can be computed directly with [`Threefry4x64::block`].
+    Threefry4x64,
+    /// See [the WELL page](http://www.iro.umontreal.ca/~panneton/WELLRNG.html) (Panneton, L'Ecuyer and Matsumoto).
+    /// 1024 bits of state with 32 bits of output, matching the reference WELL1024a.
+    Well1024a,
+    /// See [the WELL page](http://www.iro.umontreal.ca/~panneton/WELLRNG.html) (Panneton, L'Ecuyer and Matsumoto).
+    /// 512 bits of state with 32 bits of output, matching the reference WELL512a.
+    Well512a,
+    /// See [wyhash](https://github.com/wangyi-fudan/wyhash).  64 bits of state and a single
+    /// 128-bit multiply per output.
+    WyRand,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 128 bits of state with the `+` scrambler, best suited to generating floats.
+    Xoroshiro128P,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 128 bits of state with the `++` scrambler.
+    Xoroshiro128PP,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 128 bits of state with the `**` scrambler.
+    Xoroshiro128SS,
     /// See [the Xorhsift Wikipedia page](https://en.wikipedia.org/wiki/Xorshift).
     XorshiftPlus,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 128 bits of state in 32-bit words with the `++` scrambler, producing 32 bits per call.
+    Xoshiro128PP,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 128 bits of state in 32-bit words with the `**` scrambler, producing 32 bits per call.
+    Xoshiro128SS,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 256 bits of state with the `+` scrambler, best suited to generating floats.
+    Xoshiro256P,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 256 bits of state with the `++` scrambler.
+    Xoshiro256PP,
     /// See [the Xorshift Wikipedia page](https://en.wikipedia.org/wiki/Xorshift).
-    Xoshiro256SS
+    Xoshiro256SS,
+    /// See [the xoshiro/xoroshiro generators page](https://prng.di.unimi.it/).
+    /// 512 bits of state with the `**` scrambler.
+    Xoshiro512SS,
 }
 
 pub const DEFAULT_RANDOM_NUMBER_ALGORITHM: RandomNumberAlgorithm = RandomNumberAlgorithm::Xoshiro256SS;
 
-/// Generates a new default random number generator seeded with the current time in nanos.
+/// Generates a new default random number generator seeded with random bytes from the operating system.
 /// This is the easiest way to get started using the library.
 pub fn new_default() -> Box<dyn RandomNumber> {
     new(DEFAULT_RANDOM_NUMBER_ALGORITHM)
 }
 
 /// Generates a new random number generator using the given implementation, seeded with
-/// the current time in nanos.
+/// random bytes from the operating system.
+/// 
+/// # Panics
+/// 
+/// If the operating system can't supply random bytes.  See [`try_new()`] and [`from_time()`] for a fallback.
 pub fn new(algorithm: RandomNumberAlgorithm) -> Box<dyn RandomNumber> {
-    match algorithm {
-        RandomNumberAlgorithm::MersenneTwister => Box::new(MersenneTwister::new()),
-        RandomNumberAlgorithm::MiddleSquareWeylSequence => Box::new(MiddleSquaresWeylSequence::new()),
-        RandomNumberAlgorithm::XorshiftPlus => Box::new(XorshiftPlus::new()),
-        RandomNumberAlgorithm::Xoshiro256SS => Box::new(Xoshiro256SS::new()),
-    }
+    try_new(algorithm).expect("unable to read random bytes from the operating system")
+}
+
+/// Like [`new()`], but returns the error encountered reading random bytes from the operating system.
+pub fn try_new(algorithm: RandomNumberAlgorithm) -> io::Result<Box<dyn RandomNumber>> {
+    Ok(match algorithm {
+        RandomNumberAlgorithm::ChaCha8 => Box::new(ChaCha8::try_new()?),
+        RandomNumberAlgorithm::ChaCha12 => Box::new(ChaCha12::try_new()?),
+        RandomNumberAlgorithm::ChaCha20 => Box::new(ChaCha20::try_new()?),
+        RandomNumberAlgorithm::Jsf64 => Box::new(Jsf64::try_new()?),
+        RandomNumberAlgorithm::Lehmer64 => Box::new(Lehmer64::try_new()?),
+        RandomNumberAlgorithm::Mcg128 => Box::new(Mcg128::try_new()?),
+        RandomNumberAlgorithm::MersenneTwister => Box::new(MersenneTwister::try_new()?),
+        RandomNumberAlgorithm::MersenneTwisterLegacy => Box::new(MersenneTwisterLegacy::try_new()?),
+        RandomNumberAlgorithm::MT19937 => Box::new(MT19937::try_new()?),
+        RandomNumberAlgorithm::MiddleSquareWeylSequence => Box::new(MiddleSquaresWeylSequence::try_new()?),
+        RandomNumberAlgorithm::Pcg32 => Box::new(Pcg32::try_new()?),
+        RandomNumberAlgorithm::Pcg64 => Box::new(Pcg64::try_new()?),
+        RandomNumberAlgorithm::Pcg64Dxsm => Box::new(Pcg64Dxsm::try_new()?),
+        RandomNumberAlgorithm::Philox4x32 => Box::new(Philox4x32::try_new()?),
+        RandomNumberAlgorithm::RomuDuoJr => Box::new(RomuDuoJr::try_new()?),
+        RandomNumberAlgorithm::RomuTrio => Box::new(RomuTrio::try_new()?),
+        RandomNumberAlgorithm::Sfc64 => Box::new(Sfc64::try_new()?),
+        RandomNumberAlgorithm::Sfmt19937 => Box::new(Sfmt19937::try_new()?),
+        RandomNumberAlgorithm::SplitMix64 => Box::new(SplitMix::try_new()?),
+        RandomNumberAlgorithm::Squares32 => Box::new(Squares32::try_new()?),
+        RandomNumberAlgorithm::Squares64 => Box::new(Squares64::try_new()?),
+        RandomNumberAlgorithm::Threefry4x64 => Box::new(Threefry4x64::try_new()?),
+        RandomNumberAlgorithm::Well1024a => Box::new(Well1024a::try_new()?),
+        RandomNumberAlgorithm::Well512a => Box::new(Well512a::try_new()?),
+        RandomNumberAlgorithm::WyRand => Box::new(WyRand::try_new()?),
+        RandomNumberAlgorithm::Xoroshiro128P => Box::new(Xoroshiro128P::try_new()?),
+        RandomNumberAlgorithm::Xoroshiro128PP => Box::new(Xoroshiro128PP::try_new()?),
+        RandomNumberAlgorithm::Xoroshiro128SS => Box::new(Xoroshiro128SS::try_new()?),
+        RandomNumberAlgorithm::XorshiftPlus => Box::new(XorshiftPlus::try_new()?),
+        RandomNumberAlgorithm::Xoshiro128PP => Box::new(Xoshiro128PP::try_new()?),
+        RandomNumberAlgorithm::Xoshiro128SS => Box::new(Xoshiro128SS::try_new()?),
+        RandomNumberAlgorithm::Xoshiro256P => Box::new(Xoshiro256P::try_new()?),
+        RandomNumberAlgorithm::Xoshiro256PP => Box::new(Xoshiro256PP::try_new()?),
+        RandomNumberAlgorithm::Xoshiro256SS => Box::new(Xoshiro256SS::try_new()?),
+        RandomNumberAlgorithm::Xoshiro512SS => Box::new(Xoshiro512SS::try_new()?),
+    })
+}
+
+/// Generates a new random number generator using the given implementation, seeded with the
+/// current time.  Only use it as a fallback when the operating system can't supply random bytes.
+pub fn from_time(algorithm: RandomNumberAlgorithm) -> Box<dyn RandomNumber> {
+    from_seed(algorithm, seeds::current_nanos())
 }
 
 /// Generates a new default random number generator, seeded with the given `u64`.
@@ -249,9 +1075,426 @@ pub fn from_seed_default(seed: u64) -> Box<dyn RandomNumber> {
 /// the given `u64`.
 pub fn from_seed(algorithm: RandomNumberAlgorithm, seed: u64) -> Box<dyn RandomNumber> {
     match algorithm {
+        RandomNumberAlgorithm::ChaCha8 => Box::new(ChaCha8::from_seed(seed)),
+        RandomNumberAlgorithm::ChaCha12 => Box::new(ChaCha12::from_seed(seed)),
+        RandomNumberAlgorithm::ChaCha20 => Box::new(ChaCha20::from_seed(seed)),
+        RandomNumberAlgorithm::Jsf64 => Box::new(Jsf64::from_seed(seed)),
+        RandomNumberAlgorithm::Lehmer64 => Box::new(Lehmer64::from_seed(seed)),
+        RandomNumberAlgorithm::Mcg128 => Box::new(Mcg128::from_seed(seed)),
         RandomNumberAlgorithm::MersenneTwister => Box::new(MersenneTwister::from_seed(seed)),
+        RandomNumberAlgorithm::MersenneTwisterLegacy => Box::new(MersenneTwisterLegacy::from_seed(seed)),
+        RandomNumberAlgorithm::MT19937 => Box::new(MT19937::from_seed(seed)),
         RandomNumberAlgorithm::MiddleSquareWeylSequence => Box::new(MiddleSquaresWeylSequence::from_seed(seed)),
+        RandomNumberAlgorithm::Pcg32 => Box::new(Pcg32::from_seed(seed)),
+        RandomNumberAlgorithm::Pcg64 => Box::new(Pcg64::from_seed(seed)),
+        RandomNumberAlgorithm::Pcg64Dxsm => Box::new(Pcg64Dxsm::from_seed(seed)),
+        RandomNumberAlgorithm::Philox4x32 => Box::new(Philox4x32::from_seed(seed)),
+        RandomNumberAlgorithm::RomuDuoJr => Box::new(RomuDuoJr::from_seed(seed)),
+        RandomNumberAlgorithm::RomuTrio => Box::new(RomuTrio::from_seed(seed)),
+        RandomNumberAlgorithm::Sfc64 => Box::new(Sfc64::from_seed(seed)),
+        RandomNumberAlgorithm::Sfmt19937 => Box::new(Sfmt19937::from_seed(seed)),
+        RandomNumberAlgorithm::SplitMix64 => Box::new(SplitMix::from_seed(seed)),
+        RandomNumberAlgorithm::Squares32 => Box::new(Squares32::from_seed(seed)),
+        RandomNumberAlgorithm::Squares64 => Box::new(Squares64::from_seed(seed)),
+        RandomNumberAlgorithm::Threefry4x64 => Box::new(Threefry4x64::from_seed(seed)),
+        RandomNumberAlgorithm::Well1024a => Box::new(Well1024a::from_seed(seed)),
+        RandomNumberAlgorithm::Well512a => Box::new(Well512a::from_seed(seed)),
+        RandomNumberAlgorithm::WyRand => Box::new(WyRand::from_seed(seed)),
+        RandomNumberAlgorithm::Xoroshiro128P => Box::new(Xoroshiro128P::from_seed(seed)),
+        RandomNumberAlgorithm::Xoroshiro128PP => Box::new(Xoroshiro128PP::from_seed(seed)),
+        RandomNumberAlgorithm::Xoroshiro128SS => Box::new(Xoroshiro128SS::from_seed(seed)),
         RandomNumberAlgorithm::XorshiftPlus => Box::new(XorshiftPlus::from_seed(seed)),
+        RandomNumberAlgorithm::Xoshiro128PP => Box::new(Xoshiro128PP::from_seed(seed)),
+        RandomNumberAlgorithm::Xoshiro128SS => Box::new(Xoshiro128SS::from_seed(seed)),
+        RandomNumberAlgorithm::Xoshiro256P => Box::new(Xoshiro256P::from_seed(seed)),
+        RandomNumberAlgorithm::Xoshiro256PP => Box::new(Xoshiro256PP::from_seed(seed)),
         RandomNumberAlgorithm::Xoshiro256SS => Box::new(Xoshiro256SS::from_seed(seed)),
+        RandomNumberAlgorithm::Xoshiro512SS => Box::new(Xoshiro512SS::from_seed(seed)),
+    }
+}
+
+/// Generates a new random number generator using the given implementation, with its entire state set
+/// from `bytes`.  See [`RandomNumberInit::from_seed_bytes`] and [`seed_bytes`].
+pub fn from_seed_bytes(algorithm: RandomNumberAlgorithm, bytes: &[u8]) -> Result<Box<dyn RandomNumber>, SeedError> {
+    match algorithm {
+        RandomNumberAlgorithm::ChaCha8 => Ok(Box::new(ChaCha8::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::ChaCha12 => Ok(Box::new(ChaCha12::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::ChaCha20 => Ok(Box::new(ChaCha20::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Jsf64 => Ok(Box::new(Jsf64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Lehmer64 => Ok(Box::new(Lehmer64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Mcg128 => Ok(Box::new(Mcg128::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::MersenneTwister => Ok(Box::new(MersenneTwister::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::MersenneTwisterLegacy => Ok(Box::new(MersenneTwisterLegacy::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::MT19937 => Ok(Box::new(MT19937::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::MiddleSquareWeylSequence => Ok(Box::new(MiddleSquaresWeylSequence::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Pcg32 => Ok(Box::new(Pcg32::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Pcg64 => Ok(Box::new(Pcg64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Pcg64Dxsm => Ok(Box::new(Pcg64Dxsm::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Philox4x32 => Ok(Box::new(Philox4x32::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::RomuDuoJr => Ok(Box::new(RomuDuoJr::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::RomuTrio => Ok(Box::new(RomuTrio::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Sfc64 => Ok(Box::new(Sfc64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Sfmt19937 => Ok(Box::new(Sfmt19937::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::SplitMix64 => Ok(Box::new(SplitMix::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Squares32 => Ok(Box::new(Squares32::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Squares64 => Ok(Box::new(Squares64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Threefry4x64 => Ok(Box::new(Threefry4x64::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Well1024a => Ok(Box::new(Well1024a::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Well512a => Ok(Box::new(Well512a::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::WyRand => Ok(Box::new(WyRand::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoroshiro128P => Ok(Box::new(Xoroshiro128P::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoroshiro128PP => Ok(Box::new(Xoroshiro128PP::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoroshiro128SS => Ok(Box::new(Xoroshiro128SS::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::XorshiftPlus => Ok(Box::new(XorshiftPlus::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro128PP => Ok(Box::new(Xoshiro128PP::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro128SS => Ok(Box::new(Xoshiro128SS::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro256P => Ok(Box::new(Xoshiro256P::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro256PP => Ok(Box::new(Xoshiro256PP::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro256SS => Ok(Box::new(Xoshiro256SS::from_seed_bytes(bytes)?)),
+        RandomNumberAlgorithm::Xoshiro512SS => Ok(Box::new(Xoshiro512SS::from_seed_bytes(bytes)?)),
+    }
+}
+
+/// The number of bytes [`from_seed_bytes`] expects for the given implementation.
+pub fn seed_bytes(algorithm: RandomNumberAlgorithm) -> usize {
+    match algorithm {
+        RandomNumberAlgorithm::ChaCha8 => ChaCha8::SEED_BYTES,
+        RandomNumberAlgorithm::ChaCha12 => ChaCha12::SEED_BYTES,
+        RandomNumberAlgorithm::ChaCha20 => ChaCha20::SEED_BYTES,
+        RandomNumberAlgorithm::Jsf64 => Jsf64::SEED_BYTES,
+        RandomNumberAlgorithm::Lehmer64 => Lehmer64::SEED_BYTES,
+        RandomNumberAlgorithm::Mcg128 => Mcg128::SEED_BYTES,
+        RandomNumberAlgorithm::MersenneTwister => MersenneTwister::SEED_BYTES,
+        RandomNumberAlgorithm::MersenneTwisterLegacy => MersenneTwisterLegacy::SEED_BYTES,
+        RandomNumberAlgorithm::MT19937 => MT19937::SEED_BYTES,
+        RandomNumberAlgorithm::MiddleSquareWeylSequence => MiddleSquaresWeylSequence::SEED_BYTES,
+        RandomNumberAlgorithm::Pcg32 => Pcg32::SEED_BYTES,
+        RandomNumberAlgorithm::Pcg64 => Pcg64::SEED_BYTES,
+        RandomNumberAlgorithm::Pcg64Dxsm => Pcg64Dxsm::SEED_BYTES,
+        RandomNumberAlgorithm::Philox4x32 => Philox4x32::SEED_BYTES,
+        RandomNumberAlgorithm::RomuDuoJr => RomuDuoJr::SEED_BYTES,
+        RandomNumberAlgorithm::RomuTrio => RomuTrio::SEED_BYTES,
+        RandomNumberAlgorithm::Sfc64 => Sfc64::SEED_BYTES,
+        RandomNumberAlgorithm::Sfmt19937 => Sfmt19937::SEED_BYTES,
+        RandomNumberAlgorithm::SplitMix64 => SplitMix::SEED_BYTES,
+        RandomNumberAlgorithm::Squares32 => Squares32::SEED_BYTES,
+        RandomNumberAlgorithm::Squares64 => Squares64::SEED_BYTES,
+        RandomNumberAlgorithm::Threefry4x64 => Threefry4x64::SEED_BYTES,
+        RandomNumberAlgorithm::Well1024a => Well1024a::SEED_BYTES,
+        RandomNumberAlgorithm::Well512a => Well512a::SEED_BYTES,
+        RandomNumberAlgorithm::WyRand => WyRand::SEED_BYTES,
+        RandomNumberAlgorithm::Xoroshiro128P => Xoroshiro128P::SEED_BYTES,
+        RandomNumberAlgorithm::Xoroshiro128PP => Xoroshiro128PP::SEED_BYTES,
+        RandomNumberAlgorithm::Xoroshiro128SS => Xoroshiro128SS::SEED_BYTES,
+        RandomNumberAlgorithm::XorshiftPlus => XorshiftPlus::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro128PP => Xoshiro128PP::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro128SS => Xoshiro128SS::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro256P => Xoshiro256P::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro256PP => Xoshiro256PP::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro256SS => Xoshiro256SS::SEED_BYTES,
+        RandomNumberAlgorithm::Xoshiro512SS => Xoshiro512SS::SEED_BYTES,
+    }
+}
+
+/// Restores a generator from a state saved with [`RandomNumber::snapshot`] or [`RandomNumberState::save_state`],
+/// using the algorithm recorded in the state.
+pub fn restore_state(state: &[u8]) -> Result<Box<dyn RandomNumber>, StateError> {
+    match state::algorithm(state)? {
+        RandomNumberAlgorithm::ChaCha8 => Ok(Box::new(ChaCha8::restore_state(state)?)),
+        RandomNumberAlgorithm::ChaCha12 => Ok(Box::new(ChaCha12::restore_state(state)?)),
+        RandomNumberAlgorithm::ChaCha20 => Ok(Box::new(ChaCha20::restore_state(state)?)),
+        RandomNumberAlgorithm::Jsf64 => Ok(Box::new(Jsf64::restore_state(state)?)),
+        RandomNumberAlgorithm::Lehmer64 => Ok(Box::new(Lehmer64::restore_state(state)?)),
+        RandomNumberAlgorithm::Mcg128 => Ok(Box::new(Mcg128::restore_state(state)?)),
+        RandomNumberAlgorithm::MersenneTwister => Ok(Box::new(MersenneTwister::restore_state(state)?)),
+        RandomNumberAlgorithm::MersenneTwisterLegacy => Ok(Box::new(MersenneTwisterLegacy::restore_state(state)?)),
+        RandomNumberAlgorithm::MT19937 => Ok(Box::new(MT19937::restore_state(state)?)),
+        RandomNumberAlgorithm::MiddleSquareWeylSequence => Ok(Box::new(MiddleSquaresWeylSequence::restore_state(state)?)),
+        RandomNumberAlgorithm::Pcg32 => Ok(Box::new(Pcg32::restore_state(state)?)),
+        RandomNumberAlgorithm::Pcg64 => Ok(Box::new(Pcg64::restore_state(state)?)),
+        RandomNumberAlgorithm::Pcg64Dxsm => Ok(Box::new(Pcg64Dxsm::restore_state(state)?)),
+        RandomNumberAlgorithm::Philox4x32 => Ok(Box::new(Philox4x32::restore_state(state)?)),
+        RandomNumberAlgorithm::RomuDuoJr => Ok(Box::new(RomuDuoJr::restore_state(state)?)),
+        RandomNumberAlgorithm::RomuTrio => Ok(Box::new(RomuTrio::restore_state(state)?)),
+        RandomNumberAlgorithm::Sfc64 => Ok(Box::new(Sfc64::restore_state(state)?)),
+        RandomNumberAlgorithm::Sfmt19937 => Ok(Box::new(Sfmt19937::restore_state(state)?)),
+        RandomNumberAlgorithm::SplitMix64 => Ok(Box::new(SplitMix::restore_state(state)?)),
+        RandomNumberAlgorithm::Squares32 => Ok(Box::new(Squares32::restore_state(state)?)),
+        RandomNumberAlgorithm::Squares64 => Ok(Box::new(Squares64::restore_state(state)?)),
+        RandomNumberAlgorithm::Threefry4x64 => Ok(Box::new(Threefry4x64::restore_state(state)?)),
+        RandomNumberAlgorithm::Well1024a => Ok(Box::new(Well1024a::restore_state(state)?)),
+        RandomNumberAlgorithm::Well512a => Ok(Box::new(Well512a::restore_state(state)?)),
+        RandomNumberAlgorithm::WyRand => Ok(Box::new(WyRand::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoroshiro128P => Ok(Box::new(Xoroshiro128P::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoroshiro128PP => Ok(Box::new(Xoroshiro128PP::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoroshiro128SS => Ok(Box::new(Xoroshiro128SS::restore_state(state)?)),
+        RandomNumberAlgorithm::XorshiftPlus => Ok(Box::new(XorshiftPlus::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro128PP => Ok(Box::new(Xoshiro128PP::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro128SS => Ok(Box::new(Xoshiro128SS::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro256P => Ok(Box::new(Xoshiro256P::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro256PP => Ok(Box::new(Xoshiro256PP::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro256SS => Ok(Box::new(Xoshiro256SS::restore_state(state)?)),
+        RandomNumberAlgorithm::Xoshiro512SS => Ok(Box::new(Xoshiro512SS::restore_state(state)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_mul_u128_test() {
+        assert_eq!(widening_mul_u128(0, u128::MAX), (0, 0));
+        assert_eq!(widening_mul_u128(1 << 64, 1 << 64), (1, 0));
+        assert_eq!(widening_mul_u128(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul_u128(u128::MAX, 3), (2, u128::MAX - 2));
+    }
+
+    #[test]
+    fn next_below_test() {
+        let mut rand = SplitMix::from_seed(0);
+
+        for _ in 0..1000 {
+            assert_eq!(rand.next_u32_below(1), 0);
+            assert!(rand.next_u32_below(3) < 3);
+            assert!(rand.next_u64_below((1 << 63) + 1) <= 1 << 63);
+            assert!(rand.next_u128_below(u128::MAX) < u128::MAX);
+            assert!(rand.next_usize_below(10) < 10);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_below_zero_test() {
+        SplitMix::from_seed(0).next_u64_below(0);
+    }
+
+    #[test]
+    fn next_range_test() {
+        let mut rand = SplitMix::from_seed(0);
+
+        for _ in 0..1000 {
+            let n = rand.next_i8_range(-128, -126);
+            assert!(n == -128 || n == -127);
+
+            let n = rand.next_i64_range_inclusive(-1, 1);
+            assert!((-1..=1).contains(&n));
+
+            assert_eq!(rand.next_u16_range_inclusive(7, 7), 7);
+            assert!(rand.next_u128_range(u128::MAX - 2, u128::MAX) >= u128::MAX - 2);
+            assert!(rand.next_isize_range(-5, 5) < 5);
+        }
+    }
+
+    #[test]
+    fn next_range_inclusive_full_test() {
+        let mut rand = SplitMix::from_seed(0);
+        let mut expected = SplitMix::from_seed(0);
+
+        // the entire range doesn't need any rejection
+        assert_eq!(rand.next_i64_range_inclusive(i64::MIN, i64::MAX), expected.next_u64() as i64);
+        assert_eq!(rand.next_u32_range_inclusive(0, u32::MAX), expected.next_u32());
+        assert_eq!(rand.next_i128_range_inclusive(i128::MIN, i128::MAX), expected.next_u128() as i128);
+        // narrow types widen the span instead
+        rand.next_i8_range_inclusive(i8::MIN, i8::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_range_empty_test() {
+        SplitMix::from_seed(0).next_i32_range(3, 3);
+    }
+
+    // returns the given values in order, then repeats them
+    #[derive(Debug)]
+    struct Sequence {
+        values: Vec<u64>,
+        index: usize
+    }
+
+    impl Sequence {
+        fn new(values: &[u64]) -> Sequence {
+            Sequence { values: values.to_vec(), index: 0 }
+        }
+    }
+
+    impl RandomNumber for Sequence {
+        fn next_u64(&mut self) -> u64 {
+            let n = self.values[self.index % self.values.len()];
+            self.index += 1;
+            n
+        }
+    }
+
+    #[test]
+    fn next_float_interval_test() {
+        let mut zeros = Sequence::new(&[0]);
+
+        assert_eq!(zeros.next_f64_open(), TWO_53_INVERSE);
+        assert_eq!(zeros.next_f64_closed(), 0.0);
+        assert_eq!(zeros.next_f64_open_closed(), TWO_53_INVERSE);
+        assert_eq!(zeros.next_f32_open(), TWO_23_INVERSE);
+        assert_eq!(zeros.next_f32_closed(), 0.0);
+        assert_eq!(zeros.next_f32_open_closed(), TWO_23_INVERSE);
+
+        let mut ones = Sequence::new(&[u64::MAX]);
+
+        assert_eq!(ones.next_f64_open(), 1.0 - TWO_53_INVERSE);
+        assert_eq!(ones.next_f64_closed(), 1.0);
+        assert_eq!(ones.next_f64_open_closed(), 1.0);
+        assert_eq!(ones.next_f32_open(), 1.0 - TWO_23_INVERSE);
+        assert_eq!(ones.next_f32_closed(), 1.0);
+        assert_eq!(ones.next_f32_open_closed(), 1.0);
+    }
+
+    #[test]
+    fn next_float_open_test() {
+        let mut rand = SplitMix::from_seed(0);
+
+        for _ in 0..1000 {
+            let f = rand.next_f64_open();
+            assert!(f > 0.0 && f < 1.0);
+
+            let f = rand.next_f32_open();
+            assert!(f > 0.0 && f < 1.0);
+
+            assert!(rand.next_f64_open_closed() > 0.0);
+            assert!(rand.next_f32_open_closed() > 0.0);
+        }
+    }
+
+    #[test]
+    fn next_float_full_precision_test() {
+        assert_eq!(Sequence::new(&[u64::MAX]).next_f64_full_precision(), 1.0 - TWO_53_INVERSE);
+        assert_eq!(Sequence::new(&[1, 0]).next_f64_full_precision(), 2f64.powi(-64));
+        assert_eq!(Sequence::new(&[0]).next_f64_full_precision(), 0.0);
+        assert_eq!(Sequence::new(&[u64::MAX]).next_f32_full_precision(), 1.0 - TWO_23_INVERSE / 2.0);
+        assert_eq!(Sequence::new(&[1 << 32, 0]).next_f32_full_precision(), 2f32.powi(-32));
+        assert_eq!(Sequence::new(&[0]).next_f32_full_precision(), 0.0);
+    }
+
+    #[test]
+    fn next_float_full_precision_subnormal_test() {
+        // 1024 leading zeros, then the largest mantissa truncated to a multiple of 2^-1074
+        let mut values = vec![0; 16];
+        values.extend_from_slice(&[1 << 63, u64::MAX]);
+
+        assert_eq!(Sequence::new(&values).next_f64_full_precision(), f64::from_bits((1 << 50) - 1));
+
+        let mut values = vec![0; 4];
+        values.extend_from_slice(&[1 << 63, u64::MAX]);
+
+        assert_eq!(Sequence::new(&values).next_f32_full_precision(), f32::from_bits((1 << 21) - 1));
+    }
+
+    #[test]
+    fn next_float_full_precision_distribution_test() {
+        let mut rand = SplitMix::from_seed(0);
+        let mut below_quarter = 0;
+        let mut off_grid = 0;
+
+        for _ in 0..100_000 {
+            let f = rand.next_f64_full_precision();
+            assert!((0.0..1.0).contains(&f));
+
+            if f < 0.25 {
+                below_quarter += 1;
+            }
+
+            // next_f64 can only return multiples of 2^-53
+            if (f * TWO_53).fract() != 0.0 {
+                off_grid += 1;
+            }
+        }
+
+        assert!(below_quarter > 24_000 && below_quarter < 26_000);
+        assert!(off_grid > 0);
+    }
+
+    #[test]
+    fn fill_bytes_test() {
+        let mut rand = SplitMix::from_seed(0);
+        let mut expected = SplitMix::from_seed(0);
+        let mut bytes = [0; 13];
+
+        rand.fill_bytes(&mut bytes);
+
+        assert_eq!(bytes[0..8], expected.next_u64().to_le_bytes());
+        assert_eq!(bytes[8..13], expected.next_u64().to_le_bytes()[0..5]);
+        assert_eq!(rand.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn fill_u32_test() {
+        let mut rand = SplitMix::from_seed(0);
+        let mut expected = SplitMix::from_seed(0);
+        let mut ns = [0; 3];
+
+        rand.fill_u32(&mut ns);
+
+        let n = expected.next_u64();
+        assert_eq!(ns, [(n >> 32) as u32, n as u32, expected.next_u32()]);
+    }
+
+    #[test]
+    fn fill_u64_and_f64_test() {
+        let mut rand: Box<dyn RandomNumber> = Box::new(SplitMix::from_seed(0));
+        let mut expected = SplitMix::from_seed(0);
+        let mut u64s = [0; 5];
+        let mut f64s = [0.0; 5];
+
+        rand.fill_u64(&mut u64s);
+        rand.fill_f64(&mut f64s);
+
+        for n in u64s.iter() {
+            assert_eq!(*n, expected.next_u64());
+        }
+
+        for f in f64s.iter() {
+            assert_eq!(*f, expected.next_f64());
+        }
+    }
+
+    fn first_f64<R: RandomNumber>(mut rand: R) -> f64 {
+        rand.next_f64()
+    }
+
+    #[test]
+    fn forward_random_number_test() {
+        // MT19937 overrides next_f64, which a wrapper mustn't lose
+        let boxed: Box<dyn RandomNumber> = Box::new(MT19937::from_seed(1));
+        let mut borrowed = MT19937::from_seed(1);
+        let mut expected = MT19937::from_seed(1);
+
+        assert!(boxed.snapshot().is_some());
+        assert_eq!(first_f64(boxed), expected.next_f64());
+        assert_eq!(first_f64(&mut borrowed), MT19937::from_seed(1).next_f64());
+        assert_eq!(borrowed.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut rand = SplitMix::from_seed(0);
+        let mut expected = SplitMix::from_seed(0);
+
+        let ns: Vec<u64> = rand.iter_u64().take(3).collect();
+        assert_eq!(ns, vec![expected.next_u64(), expected.next_u64(), expected.next_u64()]);
+
+        // the iterator borrows the generator, so it carries on from there
+        assert_eq!(rand.iter_f64().next(), Some(expected.next_f64()));
+        assert_eq!(rand.iter_u8().take(10).size_hint(), (10, Some(10)));
+        assert_eq!(rand.iter_bool().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn iter_boxed_test() {
+        let mut rand = from_seed(RandomNumberAlgorithm::MT19937, 0);
+        let mut expected = MT19937::from_seed(0);
+
+        // MT19937's own next_f64 is kept through the box
+        expected.next_f64();
+        expected.next_f64();
+
+        assert_eq!(rand.iter_f64().nth(2), Some(expected.next_f64()));
     }
 }
@@ -0,0 +1,197 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    // from Lemire's lehmer64
+    pub const MULTIPLIER: u128 = 0xDA942042E4DD58B5;
+}
+
+/// Lehmer's multiplicative generator with 128 bits of state, returning the high 64 bits, as in Daniel Lemire's
+/// `lehmer64`.  One multiplication per output, so it's one of the fastest generators on hardware with a 64 x 64 to
+/// 128-bit multiply, and it passes BigCrush.
+///
+/// The state is always odd, giving a period of 2^126.  See [`crate::Mcg128`] to choose the multiplier.
+#[derive(Debug, Clone)]
+pub struct Lehmer64 {
+    state: u128
+}
+
+impl RandomNumberInit for Lehmer64 {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Lehmer64> {
+        let mut seed = [0; 2];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(Lehmer64 { state: super::odd_state(seed) })
+    }
+
+    fn from_seed(seed: u64) -> Lehmer64 {
+        lehmer64_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// The state, least significant byte first.  It must be odd.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Lehmer64, SeedError> {
+        Ok(Lehmer64 { state: super::odd_state_from_seed_bytes(bytes)? })
+    }
+}
+
+impl RandomNumberState for Lehmer64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::LEHMER64);
+        writer.write_u128(self.state);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Lehmer64, StateError> {
+        let mut reader = StateReader::new(state, tags::LEHMER64)?;
+        let state = reader.read_u128()?;
+
+        reader.finish()?;
+
+        if state % 2 == 0 {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(Lehmer64 { state })
+    }
+}
+
+impl RandomNumber for Lehmer64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(constants::MULTIPLIER);
+
+        (self.state >> 64) as u64
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Lehmer64 {
+    /// Jumps with O(log `delta`) multiplications.  The period is 2^126, so `delta` wraps modulo that.
+    fn advance(&mut self, delta: u128) {
+        self.state = super::advance_mcg(self.state, delta, constants::MULTIPLIER);
+    }
+}
+
+fn lehmer64_from_split_mix(split_mix: &mut SplitMix) -> Lehmer64 {
+    Lehmer64 {
+        state: super::odd_state([split_mix.next_u64(), split_mix.next_u64()]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE: u128 = 0x0123456789ABCDEF_FEDCBA9876543211;
+
+    #[test]
+    fn new_test() {
+        let mut lehmer64 = Lehmer64::new();
+
+        assert_eq!(lehmer64.state % 2, 1);
+
+        lehmer64.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let lehmer64 = Lehmer64::from_seed(1);
+        let mut split_mix = SplitMix::from_seed(1);
+
+        assert_eq!(lehmer64.state, crate::mcg::odd_state([split_mix.next_u64(), split_mix.next_u64()]));
+    }
+
+    #[test]
+    fn reference_test() {
+        // from Lemire's lehmer64 with the same state
+        let mut lehmer64 = Lehmer64 { state: STATE };
+
+        let expected: [u64; 6] = [
+            8402288084708883056,
+            16567443607601907502,
+            17461985860074653223,
+            15862343962365696019,
+            14709581811086914210,
+            16272291711644381069,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(lehmer64.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut lehmer64 = Lehmer64::from_seed(1);
+        let mut expected = Lehmer64::from_seed(1);
+
+        for delta in [0, 1, 2, 7, 1000] {
+            lehmer64.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(lehmer64.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut lehmer64 = Lehmer64::from_seed(1);
+        let expected = lehmer64.state;
+
+        lehmer64.advance(1 << 126);
+
+        assert_eq!(lehmer64.state, expected);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut lehmer64 = Lehmer64::from_seed_bytes(&STATE.to_le_bytes()).unwrap();
+
+        assert_eq!(lehmer64.next_u64(), 8402288084708883056);
+        assert_eq!(Lehmer64::from_seed_bytes(&(STATE - 1).to_le_bytes()).unwrap_err(), SeedError::Even);
+        assert!(Lehmer64::from_seed_bytes(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut lehmer64 = Lehmer64::from_seed(1);
+
+        lehmer64.next_u64();
+
+        let state = lehmer64.save_state();
+        let mut restored = Lehmer64::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), lehmer64.next_u64());
+        }
+
+        assert_eq!(lehmer64.snapshot(), Some(restored.save_state()));
+        assert!(Lehmer64::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Lehmer64::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+
+        let even = Lehmer64 { state: 2 }.save_state();
+
+        assert_eq!(Lehmer64::restore_state(&even).unwrap_err(), StateError::InvalidState);
+    }
+}
@@ -0,0 +1,245 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// A multiplicative congruential generator with 128 bits of state and a multiplier chosen at runtime, returning the
+/// high 64 bits of each state.  [`crate::Lehmer64`] is the same generator with a fixed 64-bit multiplier.
+///
+/// The multiplier must be 3 or 5 mod 8 and the state odd, giving a period of 2^126.  The [`RandomNumberInit`]
+/// constructors use [`Mcg128::DEFAULT_MULTIPLIER`].
+#[derive(Debug, Clone)]
+pub struct Mcg128 {
+    state: u128,
+    multiplier: u128
+}
+
+impl Mcg128 {
+    /// The 128-bit multiplier of PCG64's underlying LCG.
+    pub const DEFAULT_MULTIPLIER: u128 = 0x2360ED051FC65DA44385DF649FCCF645;
+
+    /// # Panics
+    ///
+    /// If `multiplier` isn't 3 or 5 mod 8, or `state` is even.
+    pub fn with_multiplier(multiplier: u128, state: u128) -> Mcg128 {
+        assert!(is_valid_multiplier(multiplier), "the MCG's multiplier must be 3 or 5 mod 8");
+        assert!(state % 2 == 1, "the MCG's state must be odd");
+
+        Mcg128 { state, multiplier }
+    }
+
+    pub fn multiplier(&self) -> u128 {
+        self.multiplier
+    }
+}
+
+impl RandomNumberInit for Mcg128 {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Mcg128> {
+        let mut seed = [0; 2];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER, super::odd_state(seed)))
+    }
+
+    fn from_seed(seed: u64) -> Mcg128 {
+        mcg128_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// The state, least significant byte first.  It must be odd.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Mcg128, SeedError> {
+        Ok(Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER, super::odd_state_from_seed_bytes(bytes)?))
+    }
+}
+
+impl RandomNumberState for Mcg128 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::MCG128);
+        writer.write_u128(self.state);
+        writer.write_u128(self.multiplier);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Mcg128, StateError> {
+        let mut reader = StateReader::new(state, tags::MCG128)?;
+        let state = reader.read_u128()?;
+        let multiplier = reader.read_u128()?;
+
+        reader.finish()?;
+
+        if state % 2 == 0 || !is_valid_multiplier(multiplier) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(Mcg128 { state, multiplier })
+    }
+}
+
+impl RandomNumber for Mcg128 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(self.multiplier);
+
+        (self.state >> 64) as u64
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Mcg128 {
+    /// Jumps with O(log `delta`) multiplications.  The period is 2^126, so `delta` wraps modulo that.
+    fn advance(&mut self, delta: u128) {
+        self.state = super::advance_mcg(self.state, delta, self.multiplier);
+    }
+}
+
+// the multipliers that give the full period of 2^126
+fn is_valid_multiplier(multiplier: u128) -> bool {
+    multiplier % 8 == 3 || multiplier % 8 == 5
+}
+
+fn mcg128_from_split_mix(split_mix: &mut SplitMix) -> Mcg128 {
+    let state = super::odd_state([split_mix.next_u64(), split_mix.next_u64()]);
+
+    Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER, state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Lehmer64;
+
+    const STATE: u128 = 0x0123456789ABCDEF_FEDCBA9876543211;
+
+    #[test]
+    fn new_test() {
+        let mut mcg = Mcg128::new();
+
+        assert_eq!(mcg.multiplier(), Mcg128::DEFAULT_MULTIPLIER);
+
+        mcg.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let mcg = Mcg128::from_seed(1);
+        let mut split_mix = SplitMix::from_seed(1);
+
+        assert_eq!(mcg.state, crate::mcg::odd_state([split_mix.next_u64(), split_mix.next_u64()]));
+    }
+
+    #[test]
+    fn reference_test() {
+        // from the same multiplication with C's unsigned __int128
+        let mut mcg = Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER, STATE);
+
+        let expected: [u64; 6] = [
+            11955269703900120511,
+            7695197558262514270,
+            15666326756631793646,
+            14724538693604100485,
+            6632593563146865211,
+            3491061260573921191,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(mcg.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn lehmer64_multiplier_test() {
+        let mut mcg = Mcg128::with_multiplier(0xDA942042E4DD58B5, STATE);
+        let mut lehmer64 = Lehmer64::from_seed_bytes(&STATE.to_le_bytes()).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(mcg.next_u64(), lehmer64.next_u64());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_multiplier_test() {
+        Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER + 2, STATE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn even_state_test() {
+        Mcg128::with_multiplier(Mcg128::DEFAULT_MULTIPLIER, STATE - 1);
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut mcg = Mcg128::with_multiplier(3, STATE);
+        let mut expected = Mcg128::with_multiplier(3, STATE);
+
+        for delta in [0, 1, 2, 7, 1000] {
+            mcg.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(mcg.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut mcg = Mcg128::from_seed(1);
+        let expected = mcg.state;
+
+        mcg.advance(1 << 126);
+
+        assert_eq!(mcg.state, expected);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut mcg = Mcg128::from_seed_bytes(&STATE.to_le_bytes()).unwrap();
+
+        assert_eq!(mcg.next_u64(), 11955269703900120511);
+        assert_eq!(Mcg128::from_seed_bytes(&(STATE - 1).to_le_bytes()).unwrap_err(), SeedError::Even);
+        assert!(Mcg128::from_seed_bytes(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut mcg = Mcg128::with_multiplier(0xDA942042E4DD58B5, STATE);
+
+        mcg.next_u64();
+
+        let state = mcg.save_state();
+        let mut restored = Mcg128::restore_state(&state).unwrap();
+
+        assert_eq!(restored.multiplier(), 0xDA942042E4DD58B5);
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), mcg.next_u64());
+        }
+
+        assert_eq!(mcg.snapshot(), Some(restored.save_state()));
+        assert!(Mcg128::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Mcg128::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+
+        let even_multiplier = Mcg128 { state: STATE, multiplier: 6 }.save_state();
+
+        assert_eq!(Mcg128::restore_state(&even_multiplier).unwrap_err(), StateError::InvalidState);
+    }
+}
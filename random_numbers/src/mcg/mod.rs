@@ -0,0 +1,39 @@
+pub mod lehmer64;
+pub mod mcg128;
+
+use crate::seeds;
+use crate::SeedError;
+use crate::pcg::advance_lcg_128;
+
+// Multiplicative congruential generators, state = multiplier * state % 2^128, returning the high 64 bits.  With
+// an odd state and a multiplier that's 3 or 5 mod 8 the period is 2^126.  See D. H. Lehmer, "Mathematical methods
+// in large-scale computing units", and Daniel Lemire's https://lemire.me/blog/2019/03/19/the-fastest-conventionally-random-number-generator-that-can-pass-big-crush/
+
+// an MCG is an LCG with no increment, so it jumps the same way
+fn advance_mcg(state: u128, delta: u128, multiplier: u128) -> u128 {
+    advance_lcg_128(state, delta, multiplier, 0)
+}
+
+// the low bits of the state never change, so an even state has a shorter period and zero is stuck at zero
+fn odd_state(seed: [u64; 2]) -> u128 {
+    state_from_words(seed) | 1
+}
+
+// for from_seed_bytes, which rejects even seeds rather than changing them
+fn odd_state_from_seed_bytes(bytes: &[u8]) -> Result<u128, SeedError> {
+    let mut seed = [0; 2];
+
+    seeds::u64s_from_seed_bytes(bytes, &mut seed)?;
+
+    let state = state_from_words(seed);
+
+    if state & 1 == 0 {
+        return Err(SeedError::Even);
+    }
+
+    Ok(state)
+}
+
+fn state_from_words(seed: [u64; 2]) -> u128 {
+    (seed[1] as u128) << 64 | seed[0] as u128
+}
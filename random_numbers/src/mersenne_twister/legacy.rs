@@ -0,0 +1,239 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+
+use super::constants;
+use super::temper;
+
+/// The Mersenne Twister as implemented in version 0.1.0 of this library.  Its initialization and twist both skip
+/// the last element of the state array, so its output differs from the reference MT19937-64 implementation.
+/// 
+/// Only use it to reproduce sequences generated with version 0.1.0; use [`super::MersenneTwister`] otherwise.
+#[derive(Debug, Clone)]
+pub struct MersenneTwisterLegacy {
+    index: usize,
+    mt_array: [u64; constants::N]
+}
+
+impl RandomNumberInit for MersenneTwisterLegacy {
+    const SEED_BYTES: usize = constants::N * 8;
+
+    fn try_new() -> io::Result<MersenneTwisterLegacy> {
+        let mut mta = [0; constants::N];
+
+        seeds::os_random_u64s(&mut mta)?;
+
+        // only the top 33 bits of the first element are used, so the state is only all zeros if those and every
+        // other element are
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            mta[0] = 1 << 63;
+        }
+
+        twist_mt_array(&mut mta);
+
+        Ok(MersenneTwisterLegacy {
+            index: 0,
+            mt_array: mta,
+        })
+    }
+
+    fn from_seed(seed: u64) -> MersenneTwisterLegacy {
+        let mut mta = [0; constants::N];
+
+        init_mt_array(seed, &mut mta);
+        twist_mt_array(&mut mta);
+
+        MersenneTwisterLegacy {
+            index: 0,
+            mt_array: mta,
+        }
+    }
+
+    /// Sets the MT array directly, the first output comes from twisting it.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<MersenneTwisterLegacy, SeedError> {
+        let mut mta = [0; constants::N];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut mta)?;
+
+        // only the top 33 bits of the first element are used
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            return Err(SeedError::AllZero);
+        }
+
+        Ok(MersenneTwisterLegacy {
+            index: constants::N,
+            mt_array: mta,
+        })
+    }
+}
+
+impl RandomNumberState for MersenneTwisterLegacy {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::MERSENNE_TWISTER_LEGACY);
+        writer.write_u64(self.index as u64);
+        writer.write_u64s(&self.mt_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<MersenneTwisterLegacy, StateError> {
+        let mut reader = StateReader::new(state, tags::MERSENNE_TWISTER_LEGACY)?;
+        let index = reader.read_index(constants::N)?;
+        let mut mta = [0; constants::N];
+
+        reader.read_u64s(&mut mta)?;
+        reader.finish()?;
+
+        if mta.iter().all(|n| *n == 0) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(MersenneTwisterLegacy {
+            index,
+            mt_array: mta,
+        })
+    }
+}
+
+impl RandomNumber for MersenneTwisterLegacy {
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= constants::N {
+            twist_mt_array(&mut self.mt_array);
+            self.index = 0;
+        }
+
+        let y = self.mt_array[self.index];
+        self.index += 1;
+
+        temper(y)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn init_mt_array(seed: u64, mt_array: &mut [u64; constants::N]) {
+    mt_array[0] = seed;
+    for idx in 1..(constants::N - 1) {
+        mt_array[idx] = constants::F.wrapping_mul(mt_array[idx - 1] ^ (mt_array[idx - 1] >> (constants::W - 2))).wrapping_add(idx as u64);
+    }
+}
+
+fn twist_mt_array(mt_array: &mut [u64; constants::N]) {
+    for idx in 0..(constants::N - 1) {
+        let x = (mt_array[idx] & constants::UPPER_MASK) + (mt_array[(idx + 1) % constants::N] & constants::LOWER_MASK);
+        let mut x_a = x >> 1;
+        if x % 2 == 1 {
+            x_a = x_a ^ constants::A;
+        }
+
+        mt_array[idx] = mt_array[(idx + constants::M) % constants::N] ^ x_a;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_mt_random_number_no_seed() {
+        let mut mt_rnd = MersenneTwisterLegacy::new();
+
+        for _ in 0..(constants::N * 4) {
+            // make sure we can update the inner array multiple times without panicking
+            mt_rnd.next_u64();
+        }
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut mt_rnd = MersenneTwisterLegacy::from_seed(0);
+
+        assert_eq!(mt_rnd.next_u32(), 686307269);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut mt_rnd = MersenneTwisterLegacy::from_seed(0);
+
+        assert_eq!(mt_rnd.next_u64(), 2947667278772165694);
+    }
+
+    #[test]
+    fn differs_from_reference_test() {
+        let mut legacy = MersenneTwisterLegacy::from_seed(0);
+        let mut reference = super::super::MersenneTwister::from_seed(0);
+
+        let legacy_values: Vec<u64> = (0..constants::N).map(|_| legacy.next_u64()).collect();
+        let reference_values: Vec<u64> = (0..constants::N).map(|_| reference.next_u64()).collect();
+
+        assert_ne!(legacy_values, reference_values);
+    }
+
+    #[test]
+    fn init_mt_array_test() {
+        let mut mt_array: [u64; constants::N] = [1; constants::N];
+        init_mt_array(0, &mut mt_array);
+        assert_eq!(mt_array[0], 0);
+        assert_eq!(mt_array[1], 1);
+        // the legacy implementation never initializes the last element
+        assert_eq!(mt_array[constants::N - 1], 1);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut mta = [0; constants::N];
+        init_mt_array(5489, &mut mta);
+
+        let bytes: Vec<u8> = mta.iter().flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut mt_rnd = MersenneTwisterLegacy::from_seed_bytes(&bytes).unwrap();
+        let mut expected = MersenneTwisterLegacy::from_seed(5489);
+
+        for _ in 0..1000 {
+            assert_eq!(mt_rnd.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        let mut bytes = [0; MersenneTwisterLegacy::SEED_BYTES];
+
+        assert_eq!(MersenneTwisterLegacy::from_seed_bytes(&bytes).unwrap_err(), SeedError::AllZero);
+
+        // the lower bits of the first element don't count
+        bytes[0] = 1;
+        assert_eq!(MersenneTwisterLegacy::from_seed_bytes(&bytes).unwrap_err(), SeedError::AllZero);
+
+        bytes[7] = 0x80;
+        assert!(MersenneTwisterLegacy::from_seed_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut mt_rnd = MersenneTwisterLegacy::from_seed(1);
+
+        for _ in 0..400 {
+            mt_rnd.next_u64();
+        }
+
+        let state = mt_rnd.save_state();
+        let mut restored = MersenneTwisterLegacy::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), mt_rnd.next_u64());
+        }
+
+        assert_eq!(mt_rnd.snapshot(), Some(restored.save_state()));
+        assert!(MersenneTwisterLegacy::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(MersenneTwisterLegacy::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+}
@@ -1,6 +1,18 @@
+pub mod legacy;
+pub mod mt19937;
+pub mod sfmt;
+
+use std::io;
+
 use crate::seeds;
 use crate::RandomNumber;
 use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
 
 mod constants {
     pub const W: u64 = 64; // number of bits MT is getting implemented for
@@ -26,17 +38,44 @@ mod constants {
     pub const L: u64 = 43;
 
     pub const F: u64 = 6364136223846793005;
+
+    // used by init_by_array64
+    pub const ARRAY_INIT_SEED: u64 = 19650218;
+    pub const ARRAY_F1: u64 = 3935559000370003845;
+    pub const ARRAY_F2: u64 = 2862933555777941757;
 }
 
-#[derive(Debug)]
+/// The 64-bit Mersenne Twister, MT19937-64.  Produces the same output as the reference implementation
+/// (`mt19937-64.c` by Matsumoto and Nishimura) and as C++'s `std::mt19937_64` for the same seed.
+#[derive(Debug, Clone)]
 pub struct MersenneTwister {
     index: usize,
     mt_array: [u64; constants::N]
 }
 
 impl RandomNumberInit for MersenneTwister {
-    fn new() -> MersenneTwister {
-        let seed = seeds::current_nanos();
+    const SEED_BYTES: usize = constants::N * 8;
+
+    fn try_new() -> io::Result<MersenneTwister> {
+        let mut mta = [0; constants::N];
+
+        seeds::os_random_u64s(&mut mta)?;
+
+        // only the top 33 bits of the first element are used, so the state is only all zeros if those and every
+        // other element are
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            mta[0] = 1 << 63;
+        }
+
+        twist_mt_array(&mut mta);
+
+        Ok(MersenneTwister {
+            index: 0,
+            mt_array: mta,
+        })
+    }
+
+    fn from_seed(seed: u64) -> MersenneTwister {
         let mut mta = [0; constants::N];
 
         init_mt_array(seed, &mut mta);
@@ -47,11 +86,60 @@ impl RandomNumberInit for MersenneTwister {
             mt_array: mta,
         }
     }
-    
-    fn from_seed(seed: u64) -> MersenneTwister {
+
+    /// Sets the MT array directly, the first output comes from twisting it.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<MersenneTwister, SeedError> {
         let mut mta = [0; constants::N];
 
-        init_mt_array(seed, &mut mta);
+        seeds::u64s_from_seed_bytes(bytes, &mut mta)?;
+
+        // only the top 33 bits of the first element are used
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            return Err(SeedError::AllZero);
+        }
+
+        Ok(MersenneTwister {
+            index: constants::N,
+            mt_array: mta,
+        })
+    }
+}
+
+impl RandomNumberState for MersenneTwister {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::MERSENNE_TWISTER);
+        writer.write_u64(self.index as u64);
+        writer.write_u64s(&self.mt_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<MersenneTwister, StateError> {
+        let mut reader = StateReader::new(state, tags::MERSENNE_TWISTER)?;
+        let index = reader.read_index(constants::N)?;
+        let mut mta = [0; constants::N];
+
+        reader.read_u64s(&mut mta)?;
+        reader.finish()?;
+
+        if mta.iter().all(|n| *n == 0) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(MersenneTwister {
+            index,
+            mt_array: mta,
+        })
+    }
+}
+
+impl MersenneTwister {
+    /// Seeds the generator from an array of any length, matching `init_by_array64` from the reference
+    /// implementation.  Use it to reach more than the 2^64 states available through [`RandomNumberInit::from_seed`].
+    pub fn from_seed_array(key: &[u64]) -> MersenneTwister {
+        let mut mta = [0; constants::N];
+
+        init_mt_array_by_array(key, &mut mta);
         twist_mt_array(&mut mta);
 
         MersenneTwister {
@@ -73,17 +161,83 @@ impl RandomNumber for MersenneTwister {
         
         temper(y)
     }
+
+    /// Copies out the rest of the current block, tempered, twisting as often as needed.
+    fn fill_u64(&mut self, dest: &mut [u64]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index >= constants::N {
+                twist_mt_array(&mut self.mt_array);
+                self.index = 0;
+            }
+
+            let count = (constants::N - self.index).min(dest.len() - filled);
+
+            for (n, y) in dest[filled..filled + count].iter_mut().zip(&self.mt_array[self.index..self.index + count]) {
+                *n = temper(*y);
+            }
+
+            self.index += count;
+            filled += count;
+        }
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
 }
 
 fn init_mt_array(seed: u64, mt_array: &mut [u64; constants::N]) {
     mt_array[0] = seed;
-    for idx in 1..(constants::N - 1) {
-        mt_array[idx] = constants::F.wrapping_mul(mt_array[idx - 1] ^ (mt_array[idx - 1] >> (constants::W - 2))) + (idx as u64);
+    for idx in 1..constants::N {
+        mt_array[idx] = constants::F.wrapping_mul(mt_array[idx - 1] ^ (mt_array[idx - 1] >> (constants::W - 2))).wrapping_add(idx as u64);
     }
 }
 
+fn init_mt_array_by_array(key: &[u64], mt_array: &mut [u64; constants::N]) {
+    init_mt_array(constants::ARRAY_INIT_SEED, mt_array);
+
+    // the reference implementation reads out of bounds for an empty key, so treat it as if no key was given
+    if key.is_empty() {
+        return;
+    }
+
+    let mut i = 1;
+    let mut j = 0;
+
+    for _ in 0..constants::N.max(key.len()) {
+        let prev = mt_array[i - 1] ^ (mt_array[i - 1] >> (constants::W - 2));
+        mt_array[i] = (mt_array[i] ^ prev.wrapping_mul(constants::ARRAY_F1)).wrapping_add(key[j]).wrapping_add(j as u64);
+
+        i += 1;
+        j += 1;
+        if i >= constants::N {
+            mt_array[0] = mt_array[constants::N - 1];
+            i = 1;
+        }
+        if j >= key.len() {
+            j = 0;
+        }
+    }
+
+    for _ in 0..(constants::N - 1) {
+        let prev = mt_array[i - 1] ^ (mt_array[i - 1] >> (constants::W - 2));
+        mt_array[i] = (mt_array[i] ^ prev.wrapping_mul(constants::ARRAY_F2)).wrapping_sub(i as u64);
+
+        i += 1;
+        if i >= constants::N {
+            mt_array[0] = mt_array[constants::N - 1];
+            i = 1;
+        }
+    }
+
+    // guarantees a non-zero initial array
+    mt_array[0] = 1 << 63;
+}
+
 fn twist_mt_array(mt_array: &mut [u64; constants::N]) {
-    for idx in 0..(constants::N - 1) {
+    for idx in 0..constants::N {
         let x = (mt_array[idx] & constants::UPPER_MASK) + (mt_array[(idx + 1) % constants::N] & constants::LOWER_MASK);
         let mut x_a = x >> 1;
         if x % 2 == 1 {
@@ -139,12 +293,46 @@ mod tests {
         assert_eq!(mt_rnd.next_u64(), 2947667278772165694);
     }
 
+    #[test]
+    fn mt19937_64_reference_test() {
+        // first 1000 outputs of mt19937-64.c, seeded with init_by_array64({0x12345, 0x23456, 0x34567, 0x45678}, 4)
+        let mut mt_rnd = MersenneTwister::from_seed_array(&[0x12345, 0x23456, 0x34567, 0x45678]);
+
+        let expected = include_str!("mt19937_64_reference.txt");
+
+        for line in expected.lines() {
+            assert_eq!(mt_rnd.next_u64(), line.parse::<u64>().unwrap());
+        }
+    }
+
+    #[test]
+    fn std_mt19937_64_default_seed_test() {
+        // the C++ standard requires the 10000th output of a default constructed std::mt19937_64 to be 9981545732273789042
+        let mut mt_rnd = MersenneTwister::from_seed(5489);
+
+        for _ in 0..9999 {
+            mt_rnd.next_u64();
+        }
+
+        assert_eq!(mt_rnd.next_u64(), 9981545732273789042);
+    }
+
+    #[test]
+    fn from_seed_array_empty_test() {
+        let mut mt_rnd = MersenneTwister::from_seed_array(&[]);
+        let mut expected = MersenneTwister::from_seed(constants::ARRAY_INIT_SEED);
+
+        assert_eq!(mt_rnd.next_u64(), expected.next_u64());
+    }
+
     #[test]
     fn init_mt_array_test() {
         let mut mt_array: [u64; constants::N] = [1; constants::N];
         init_mt_array(0, &mut mt_array);
         assert_eq!(mt_array[0], 0);
         assert_eq!(mt_array[1], 1);
+        // the whole array is initialized, including the last element
+        assert_ne!(mt_array[constants::N - 1], 1);
     }
 
     #[test]
@@ -157,4 +345,70 @@ mod tests {
     fn temper_test() {
         assert_eq!(temper(1), 18014535948568577);
     }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut mta = [0; constants::N];
+        init_mt_array(5489, &mut mta);
+
+        let bytes: Vec<u8> = mta.iter().flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut mt_rnd = MersenneTwister::from_seed_bytes(&bytes).unwrap();
+        let mut expected = MersenneTwister::from_seed(5489);
+
+        for _ in 0..1000 {
+            assert_eq!(mt_rnd.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        let mut bytes = [0; MersenneTwister::SEED_BYTES];
+
+        assert_eq!(MersenneTwister::from_seed_bytes(&bytes).unwrap_err(), SeedError::AllZero);
+
+        // the lower bits of the first element don't count
+        bytes[0] = 1;
+        assert_eq!(MersenneTwister::from_seed_bytes(&bytes).unwrap_err(), SeedError::AllZero);
+
+        bytes[7] = 0x80;
+        assert!(MersenneTwister::from_seed_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut mt_rnd = MersenneTwister::from_seed(1);
+
+        for _ in 0..400 {
+            mt_rnd.next_u64();
+        }
+
+        let state = mt_rnd.save_state();
+        let mut restored = MersenneTwister::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), mt_rnd.next_u64());
+        }
+
+        assert_eq!(mt_rnd.snapshot(), Some(restored.save_state()));
+        assert!(MersenneTwister::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(MersenneTwister::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn fill_u64_test() {
+        let mut mt = MersenneTwister::from_seed(5489);
+        let mut expected = MersenneTwister::from_seed(5489);
+        let mut ns = [0; 1000];
+
+        // start part way through a block, and cross several
+        mt.next_u64();
+        expected.next_u64();
+        mt.fill_u64(&mut ns);
+
+        for n in ns.iter() {
+            assert_eq!(*n, expected.next_u64());
+        }
+
+        assert_eq!(mt.next_u64(), expected.next_u64());
+    }
 }
@@ -0,0 +1,459 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+
+mod constants {
+    pub const W: u32 = 32; // number of bits MT is getting implemented for
+    pub const N: usize = 624; // size of the MT array
+
+    pub const M: usize = 397;
+    pub const R: u32 = 31;
+
+    pub const LOWER_MASK: u32 = (1 << R) - 1; // the binary number of r 1's
+    pub const UPPER_MASK: u32 = !LOWER_MASK;
+
+    pub const A: u32 = 0x9908B0DF;
+
+    pub const U: u32 = 11;
+
+    pub const S: u32 = 7;
+    pub const B: u32 = 0x9D2C5680;
+
+    pub const T: u32 = 15;
+    pub const C: u32 = 0xEFC60000;
+
+    pub const L: u32 = 18;
+
+    pub const F: u32 = 1812433253;
+
+    // used by init_by_array
+    pub const ARRAY_INIT_SEED: u32 = 19650218;
+    pub const ARRAY_F1: u32 = 1664525;
+    pub const ARRAY_F2: u32 = 1566083941;
+
+    // used to build a 53-bit f64 from two draws, like Python's random.random()
+    pub const TWO_26: f64 = 67108864.0;
+}
+
+/// The 32-bit Mersenne Twister, MT19937.  Produces the same output as the reference implementation
+/// (`mt19937ar.c` by Matsumoto and Nishimura), C++'s `std::mt19937`, and Python's `random` module.
+/// 
+/// * [`MT19937::from_seed_u32`] matches `init_genrand`, and `std::mt19937(seed)` in C++.
+/// * [`MT19937::from_seed_array`] matches `init_by_array`.
+/// * [`RandomNumberInit::from_seed`] matches `random.seed(seed)` in Python.
+/// 
+/// [`RandomNumber::next_u64`] and [`RandomNumber::next_f64`] match Python's `random.getrandbits(64)` and
+/// `random.random()` respectively, so each uses two 32-bit draws.
+#[derive(Debug, Clone)]
+pub struct MT19937 {
+    index: usize,
+    mt_array: [u32; constants::N]
+}
+
+impl MT19937 {
+    /// Seeds the generator with a 32-bit seed, matching `init_genrand` from the reference implementation.
+    pub fn from_seed_u32(seed: u32) -> MT19937 {
+        let mut mta = [0; constants::N];
+
+        init_mt_array(seed, &mut mta);
+        twist_mt_array(&mut mta);
+
+        MT19937 {
+            index: 0,
+            mt_array: mta,
+        }
+    }
+
+    /// Seeds the generator from an array of any length, matching `init_by_array` from the reference implementation.
+    pub fn from_seed_array(key: &[u32]) -> MT19937 {
+        let mut mta = [0; constants::N];
+
+        init_mt_array_by_array(key, &mut mta);
+        twist_mt_array(&mut mta);
+
+        MT19937 {
+            index: 0,
+            mt_array: mta,
+        }
+    }
+}
+
+impl RandomNumberInit for MT19937 {
+    const SEED_BYTES: usize = constants::N * 4;
+
+    fn try_new() -> io::Result<MT19937> {
+        let mut mta = [0; constants::N];
+
+        seeds::os_random_u32s(&mut mta)?;
+
+        // only the highest bit of the first element is used, so the state is only all zeros if it and every other
+        // element are
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            mta[0] = 1 << 31;
+        }
+
+        twist_mt_array(&mut mta);
+
+        Ok(MT19937 {
+            index: 0,
+            mt_array: mta,
+        })
+    }
+
+    /// Seeds the generator the same way Python's `random.seed(seed)` does: the seed is split into 32-bit words,
+    /// least significant first, and passed to `init_by_array`.
+    fn from_seed(seed: u64) -> MT19937 {
+        let low = seed as u32;
+        let high = (seed >> 32) as u32;
+
+        if high == 0 {
+            MT19937::from_seed_array(&[low])
+        } else {
+            MT19937::from_seed_array(&[low, high])
+        }
+    }
+
+    /// Sets the MT array directly, the first output comes from twisting it.  This matches Python's
+    /// `random.setstate()` with the position set to 624.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<MT19937, SeedError> {
+        let mut mta = [0; constants::N];
+
+        seeds::u32s_from_seed_bytes(bytes, &mut mta)?;
+
+        // only the highest bit of the first element is used
+        if mta[0] & constants::UPPER_MASK == 0 && mta[1..].iter().all(|n| *n == 0) {
+            return Err(SeedError::AllZero);
+        }
+
+        Ok(MT19937 {
+            index: constants::N,
+            mt_array: mta,
+        })
+    }
+}
+
+impl RandomNumberState for MT19937 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::MT19937);
+        writer.write_u64(self.index as u64);
+        writer.write_u32s(&self.mt_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<MT19937, StateError> {
+        let mut reader = StateReader::new(state, tags::MT19937)?;
+        let index = reader.read_index(constants::N)?;
+        let mut mta = [0; constants::N];
+
+        reader.read_u32s(&mut mta)?;
+        reader.finish()?;
+
+        if mta.iter().all(|n| *n == 0) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(MT19937 {
+            index,
+            mt_array: mta,
+        })
+    }
+}
+
+impl RandomNumber for MT19937 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::N {
+            twist_mt_array(&mut self.mt_array);
+            self.index = 0;
+        }
+
+        let y = self.mt_array[self.index];
+        self.index += 1;
+
+        temper(y)
+    }
+
+    /// Unlike the other 32-bit generators, the first draw makes up the lower 32 bits, to match Python's
+    /// `random.getrandbits(64)`.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) | (self.next_u32() as u64) << 32
+    }
+
+    /// Matches Python's `random.random()`, i.e. `genrand_res53` from the reference implementation.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        let a = (self.next_u32() >> 5) as f64;
+        let b = (self.next_u32() >> 6) as f64;
+
+        (a * constants::TWO_26 + b) * crate::TWO_53_INVERSE
+    }
+
+    /// Copies out the rest of the current block, tempered, twisting as often as needed.  The default would
+    /// swap each pair of values, since `next_u64` puts the first draw in the lower half.
+    fn fill_u32(&mut self, dest: &mut [u32]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index >= constants::N {
+                twist_mt_array(&mut self.mt_array);
+                self.index = 0;
+            }
+
+            let count = (constants::N - self.index).min(dest.len() - filled);
+
+            for (n, y) in dest[filled..filled + count].iter_mut().zip(&self.mt_array[self.index..self.index + count]) {
+                *n = temper(*y);
+            }
+
+            self.index += count;
+            filled += count;
+        }
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+// `init_genrand`, which SFMT's `sfmt_init_gen_rand` shares
+pub(super) fn init_mt_array(seed: u32, mt_array: &mut [u32; constants::N]) {
+    mt_array[0] = seed;
+    for idx in 1..constants::N {
+        mt_array[idx] = constants::F.wrapping_mul(mt_array[idx - 1] ^ (mt_array[idx - 1] >> (constants::W - 2))).wrapping_add(idx as u32);
+    }
+}
+
+fn init_mt_array_by_array(key: &[u32], mt_array: &mut [u32; constants::N]) {
+    init_mt_array(constants::ARRAY_INIT_SEED, mt_array);
+
+    // the reference implementation reads out of bounds for an empty key, so treat it as if no key was given
+    if key.is_empty() {
+        return;
+    }
+
+    let mut i = 1;
+    let mut j = 0;
+
+    for _ in 0..constants::N.max(key.len()) {
+        let prev = mt_array[i - 1] ^ (mt_array[i - 1] >> (constants::W - 2));
+        mt_array[i] = (mt_array[i] ^ prev.wrapping_mul(constants::ARRAY_F1)).wrapping_add(key[j]).wrapping_add(j as u32);
+
+        i += 1;
+        j += 1;
+        if i >= constants::N {
+            mt_array[0] = mt_array[constants::N - 1];
+            i = 1;
+        }
+        if j >= key.len() {
+            j = 0;
+        }
+    }
+
+    for _ in 0..(constants::N - 1) {
+        let prev = mt_array[i - 1] ^ (mt_array[i - 1] >> (constants::W - 2));
+        mt_array[i] = (mt_array[i] ^ prev.wrapping_mul(constants::ARRAY_F2)).wrapping_sub(i as u32);
+
+        i += 1;
+        if i >= constants::N {
+            mt_array[0] = mt_array[constants::N - 1];
+            i = 1;
+        }
+    }
+
+    // guarantees a non-zero initial array
+    mt_array[0] = 1 << 31;
+}
+
+fn twist_mt_array(mt_array: &mut [u32; constants::N]) {
+    for idx in 0..constants::N {
+        let x = (mt_array[idx] & constants::UPPER_MASK) | (mt_array[(idx + 1) % constants::N] & constants::LOWER_MASK);
+        let mut x_a = x >> 1;
+        if x % 2 == 1 {
+            x_a ^= constants::A;
+        }
+
+        mt_array[idx] = mt_array[(idx + constants::M) % constants::N] ^ x_a;
+    }
+}
+
+fn temper(y: u32) -> u32 {
+    let mut t = y ^ (y >> constants::U);
+    t ^= (t << constants::S) & constants::B;
+    t ^= (t << constants::T) & constants::C;
+    t ^ (t >> constants::L)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_mt_random_number_no_seed() {
+        let mut mt_rnd = MT19937::new();
+
+        for _ in 0..(constants::N * 4) {
+            // make sure we can update the inner array multiple times without panicking
+            mt_rnd.next_u32();
+        }
+    }
+
+    #[test]
+    fn mt19937ar_reference_test() {
+        // first outputs of mt19937ar.c, seeded with init_by_array({0x123, 0x234, 0x345, 0x456}, 4)
+        let mut mt_rnd = MT19937::from_seed_array(&[0x123, 0x234, 0x345, 0x456]);
+
+        let expected: [u32; 5] = [1067595299, 955945823, 477289528, 4107218783, 4228976476];
+
+        for e in expected.iter() {
+            assert_eq!(mt_rnd.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn std_mt19937_default_seed_test() {
+        // the C++ standard requires the 10000th output of a default constructed std::mt19937 to be 4123659995
+        let mut mt_rnd = MT19937::from_seed_u32(5489);
+
+        for _ in 0..9999 {
+            mt_rnd.next_u32();
+        }
+
+        assert_eq!(mt_rnd.next_u32(), 4123659995);
+    }
+
+    #[test]
+    fn std_mt19937_seed_test() {
+        // std::mt19937 rnd(42);
+        let mut mt_rnd = MT19937::from_seed_u32(42);
+
+        assert_eq!(mt_rnd.next_u32(), 1608637542);
+        assert_eq!(mt_rnd.next_u32(), 3421126067);
+    }
+
+    #[test]
+    fn python_getrandbits_test() {
+        // random.seed(42); [random.getrandbits(32) for _ in range(3)]
+        let mut mt_rnd = MT19937::from_seed(42);
+
+        assert_eq!(mt_rnd.next_u32(), 2746317213);
+        assert_eq!(mt_rnd.next_u32(), 478163327);
+        assert_eq!(mt_rnd.next_u32(), 107420369);
+    }
+
+    #[test]
+    fn python_getrandbits_64_test() {
+        // random.seed(42); random.getrandbits(64)
+        let mut mt_rnd = MT19937::from_seed(42);
+
+        assert_eq!(mt_rnd.next_u64(), 2053695854357871005);
+    }
+
+    #[test]
+    fn python_random_test() {
+        // random.seed(42); random.random(); random.random()
+        let mut mt_rnd = MT19937::from_seed(42);
+
+        assert_eq!(mt_rnd.next_f64(), 0.6394267984578837);
+        assert_eq!(mt_rnd.next_f64(), 0.025010755222666936);
+    }
+
+    #[test]
+    fn python_large_seed_test() {
+        // random.seed(2**40 + 7); random.getrandbits(32)
+        let mut mt_rnd = MT19937::from_seed((1 << 40) + 7);
+
+        assert_eq!(mt_rnd.next_u32(), 2635837658);
+    }
+
+    #[test]
+    fn python_zero_seed_test() {
+        // random.seed(0); random.getrandbits(32); random.random()
+        let mut mt_rnd = MT19937::from_seed(0);
+
+        assert_eq!(mt_rnd.next_u32(), 3626764237);
+        assert_eq!(mt_rnd.next_f64(), 0.3852453064766108);
+    }
+
+    #[test]
+    fn temper_test() {
+        assert_eq!(temper(1), 4194449);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        // random.setstate((3, tuple(list(range(1, 625)) + [624]), None)); [random.getrandbits(32) for _ in range(3)]
+        let bytes: Vec<u8> = (1..=624u32).flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut mt_rnd = MT19937::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(mt_rnd.next_u32(), 596004846);
+        assert_eq!(mt_rnd.next_u32(), 3713115539);
+        assert_eq!(mt_rnd.next_u32(), 549472674);
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(MT19937::from_seed_bytes(&[0; MT19937::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert!(MT19937::from_seed_bytes(&[0; 100]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut mt_rnd = MT19937::from_seed(1);
+
+        for _ in 0..700 {
+            mt_rnd.next_u64();
+        }
+
+        let state = mt_rnd.save_state();
+        let mut restored = MT19937::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), mt_rnd.next_u64());
+        }
+
+        assert_eq!(mt_rnd.snapshot(), Some(restored.save_state()));
+        assert!(MT19937::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(MT19937::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn fill_u32_test() {
+        let mut mt = MT19937::from_seed(5489);
+        let mut expected = MT19937::from_seed(5489);
+        let mut ns = [0; 1000];
+
+        mt.next_u32();
+        expected.next_u32();
+        mt.fill_u32(&mut ns);
+
+        for n in ns.iter() {
+            assert_eq!(*n, expected.next_u32());
+        }
+
+        assert_eq!(mt.next_u32(), expected.next_u32());
+    }
+}
@@ -0,0 +1,354 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+use super::mt19937::init_mt_array;
+
+mod constants {
+    pub const N: usize = 156; // size of the SFMT array in 128-bit words
+    pub const N32: usize = N * 4;
+
+    pub const POS1: usize = 122;
+    pub const SL1: u32 = 18;
+    pub const SL2: u32 = 1; // in bytes
+    pub const SR1: u32 = 11;
+    pub const SR2: u32 = 1; // in bytes
+
+    pub const MSK: [u32; 4] = [0xDFFFFFEF, 0xDDFECB7F, 0xBFFAFFFF, 0xBFFFFFF6];
+    pub const PARITY: [u32; 4] = [0x00000001, 0x00000000, 0x00000000, 0x13C9E684];
+}
+
+/// The SIMD-oriented Fast Mersenne Twister, SFMT19937, with a period of 2^19937 - 1.  Produces the same output as
+/// `sfmt_genrand_uint32` from the reference implementation (`SFMT.c` by Saito and Matsumoto) and as
+/// `__gnu_cxx::sfmt19937` in libstdc++.
+///
+/// Like [`super::MersenneTwister`] the whole array is regenerated at once, but in 128-bit words and without
+/// tempering, so it's faster.
+///
+/// [`RandomNumber::next_u64`] puts the first draw in the lower 32 bits, like `sfmt_genrand_uint64`.
+#[derive(Debug, Clone)]
+pub struct Sfmt19937 {
+    index: usize,
+    sfmt_array: [u32; constants::N32]
+}
+
+impl Sfmt19937 {
+    /// Seeds the generator with a 32-bit seed, matching `sfmt_init_gen_rand` from the reference implementation.
+    pub fn from_seed_u32(seed: u32) -> Sfmt19937 {
+        let mut sfmt_array = [0; constants::N32];
+
+        init_mt_array(seed, &mut sfmt_array);
+        certify_period(&mut sfmt_array);
+
+        Sfmt19937 {
+            index: constants::N32,
+            sfmt_array,
+        }
+    }
+}
+
+impl RandomNumberInit for Sfmt19937 {
+    const SEED_BYTES: usize = constants::N32 * 4;
+
+    fn try_new() -> io::Result<Sfmt19937> {
+        let mut sfmt_array = [0; constants::N32];
+
+        seeds::os_random_u32s(&mut sfmt_array)?;
+        certify_period(&mut sfmt_array);
+
+        Ok(Sfmt19937 {
+            index: constants::N32,
+            sfmt_array,
+        })
+    }
+
+    fn from_seed(seed: u64) -> Sfmt19937 {
+        sfmt_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets the SFMT array directly, the first output comes from regenerating it.  Like the reference
+    /// implementation, one bit may be flipped to make sure the period is 2^19937 - 1, so no seed is rejected.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Sfmt19937, SeedError> {
+        let mut sfmt_array = [0; constants::N32];
+
+        seeds::u32s_from_seed_bytes(bytes, &mut sfmt_array)?;
+        certify_period(&mut sfmt_array);
+
+        Ok(Sfmt19937 {
+            index: constants::N32,
+            sfmt_array,
+        })
+    }
+}
+
+impl RandomNumberState for Sfmt19937 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::SFMT19937);
+        writer.write_u64(self.index as u64);
+        writer.write_u32s(&self.sfmt_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Sfmt19937, StateError> {
+        let mut reader = StateReader::new(state, tags::SFMT19937)?;
+        let index = reader.read_index(constants::N32)?;
+        let mut sfmt_array = [0; constants::N32];
+
+        reader.read_nonzero_u32s(&mut sfmt_array)?;
+        reader.finish()?;
+
+        Ok(Sfmt19937 {
+            index,
+            sfmt_array,
+        })
+    }
+}
+
+impl RandomNumber for Sfmt19937 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::N32 {
+            twist_sfmt_array(&mut self.sfmt_array);
+            self.index = 0;
+        }
+
+        let n = self.sfmt_array[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) | (self.next_u32() as u64) << 32
+    }
+
+    /// Copies out the rest of the current block, regenerating it as often as needed.  The default would swap each
+    /// pair of values, since `next_u64` puts the first draw in the lower half.
+    fn fill_u32(&mut self, dest: &mut [u32]) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index >= constants::N32 {
+                twist_sfmt_array(&mut self.sfmt_array);
+                self.index = 0;
+            }
+
+            let count = (constants::N32 - self.index).min(dest.len() - filled);
+
+            dest[filled..filled + count].copy_from_slice(&self.sfmt_array[self.index..self.index + count]);
+
+            self.index += count;
+            filled += count;
+        }
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn sfmt_from_split_mix(split_mix: &mut SplitMix) -> Sfmt19937 {
+    let mut sfmt_array = [0; constants::N32];
+
+    for pair in sfmt_array.chunks_exact_mut(2) {
+        let n = split_mix.next_u64();
+
+        pair[0] = n as u32;
+        pair[1] = (n >> 32) as u32;
+    }
+
+    certify_period(&mut sfmt_array);
+
+    Sfmt19937 {
+        index: constants::N32,
+        sfmt_array,
+    }
+}
+
+/// Regenerates the whole array, `sfmt_gen_rand_all` from the reference implementation.  Each 128-bit word depends
+/// on the word `POS1` ahead of it and the two words before it, which have already been regenerated.
+fn twist_sfmt_array(sfmt_array: &mut [u32; constants::N32]) {
+    let mut r1 = word(sfmt_array, constants::N - 2);
+    let mut r2 = word(sfmt_array, constants::N - 1);
+
+    for i in 0..constants::N {
+        let r = recursion(word(sfmt_array, i), word(sfmt_array, (i + constants::POS1) % constants::N), r1, r2);
+
+        sfmt_array[i * 4..i * 4 + 4].copy_from_slice(&r);
+        r1 = r2;
+        r2 = r;
+    }
+}
+
+fn word(sfmt_array: &[u32; constants::N32], i: usize) -> [u32; 4] {
+    [sfmt_array[i * 4], sfmt_array[i * 4 + 1], sfmt_array[i * 4 + 2], sfmt_array[i * 4 + 3]]
+}
+
+// the 128-bit words are little endian, and SL2 and SR2 shift the whole word rather than each 32-bit part
+fn recursion(a: [u32; 4], b: [u32; 4], c: [u32; 4], d: [u32; 4]) -> [u32; 4] {
+    let x = to_words(from_words(a) << (constants::SL2 * 8));
+    let y = to_words(from_words(c) >> (constants::SR2 * 8));
+
+    std::array::from_fn(|k| a[k] ^ x[k] ^ ((b[k] >> constants::SR1) & constants::MSK[k]) ^ y[k] ^ (d[k] << constants::SL1))
+}
+
+fn from_words(w: [u32; 4]) -> u128 {
+    (w[3] as u128) << 96 | (w[2] as u128) << 64 | (w[1] as u128) << 32 | w[0] as u128
+}
+
+fn to_words(n: u128) -> [u32; 4] {
+    [n as u32, (n >> 32) as u32, (n >> 64) as u32, (n >> 96) as u32]
+}
+
+/// Makes sure the period is 2^19937 - 1 by flipping a bit of the first word if needed,
+/// `period_certification` from the reference implementation.
+fn certify_period(sfmt_array: &mut [u32; constants::N32]) {
+    let inner = sfmt_array.iter().zip(constants::PARITY.iter()).fold(0, |inner, (n, p)| inner ^ (n & p));
+
+    if inner.count_ones() % 2 == 1 {
+        return;
+    }
+
+    for (n, p) in sfmt_array.iter_mut().zip(constants::PARITY.iter()) {
+        if *p != 0 {
+            *n ^= 1 << p.trailing_zeros();
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut sfmt = Sfmt19937::new();
+
+        sfmt.next_u32();
+    }
+
+    #[test]
+    fn sfmt_reference_test() {
+        // the first values of SFMT.19937.out.txt from the reference implementation, seeded with 1234
+        let mut sfmt = Sfmt19937::from_seed_u32(1234);
+
+        let expected: [u32; 8] = [3440181298, 1564997079, 1510669302, 2930277156, 1452439940, 3796268453, 423124208, 2143818589];
+
+        for e in expected.iter() {
+            assert_eq!(sfmt.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn libstdcxx_sfmt19937_test() {
+        // __gnu_cxx::sfmt19937, default constructed and after discarding 1000 values
+        assert_eq!(Sfmt19937::from_seed_u32(5489).next_u32(), 49253815);
+
+        let mut sfmt = Sfmt19937::from_seed_u32(4321);
+
+        for _ in 0..1000 {
+            sfmt.next_u32();
+        }
+
+        assert_eq!(sfmt.next_u32(), 2716642426);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut sfmt = Sfmt19937::from_seed_u32(1234);
+
+        assert_eq!(sfmt.next_u64(), 1564997079 << 32 | 3440181298);
+    }
+
+    #[test]
+    fn certify_period_test() {
+        let mut sfmt_array = [0; constants::N32];
+
+        certify_period(&mut sfmt_array);
+
+        assert_eq!(sfmt_array[0], 1);
+
+        // already certified, so left alone
+        certify_period(&mut sfmt_array);
+
+        assert_eq!(sfmt_array[0], 1);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = vec![0; Sfmt19937::SEED_BYTES];
+        bytes[0] = 1;
+
+        let mut sfmt = Sfmt19937::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Sfmt19937::from_seed_bytes(&[0; Sfmt19937::SEED_BYTES]).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(sfmt.next_u32(), expected.next_u32());
+        }
+
+        assert!(Sfmt19937::from_seed_bytes(&[1; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut sfmt = Sfmt19937::from_seed(1);
+
+        for _ in 0..3 {
+            sfmt.next_u32();
+        }
+
+        let state = sfmt.save_state();
+        let mut restored = Sfmt19937::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), sfmt.next_u32());
+        }
+
+        assert_eq!(sfmt.snapshot(), Some(restored.save_state()));
+        assert!(Sfmt19937::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Sfmt19937::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn fill_u32_test() {
+        let mut sfmt = Sfmt19937::from_seed(1);
+        let mut expected = Sfmt19937::from_seed(1);
+        let mut ns = [0; 1000];
+
+        sfmt.next_u32();
+        expected.next_u32();
+        sfmt.fill_u32(&mut ns);
+
+        for n in ns.iter() {
+            assert_eq!(*n, expected.next_u32());
+        }
+
+        assert_eq!(sfmt.next_u32(), expected.next_u32());
+    }
+}
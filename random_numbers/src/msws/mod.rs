@@ -1,5 +1,16 @@
+pub mod squares;
+
+use std::io;
+
+use crate::seeds;
 use crate::RandomNumber;
 use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
 use crate::split_mix::SplitMix;
 
 mod constants {
@@ -10,6 +21,11 @@ mod constants {
     pub const S: u64 = 0x9f32e1cbc5e1374b;
 }
 
+/// Widynski's Middle Square Weyl Sequence: squares a 64-bit state and keeps the middle bits, with a Weyl
+/// sequence added each step so it never gets stuck.  Produces 32 bits per step.
+/// 
+/// It doesn't implement [`crate::RandomNumberAdvance`]: the Weyl sequence could be advanced in O(1), but squaring
+/// the state can't be skipped.
 #[derive(Debug)]
 pub struct MiddleSquaresWeylSequence {
     x: u64,
@@ -17,13 +33,58 @@ pub struct MiddleSquaresWeylSequence {
 }
 
 impl RandomNumberInit for MiddleSquaresWeylSequence {
-    fn new() -> MiddleSquaresWeylSequence {
-        msws_from_split_mix(&mut SplitMix::new())
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<MiddleSquaresWeylSequence> {
+        let mut seed = [0; 2];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        let mut msws = MiddleSquaresWeylSequence {
+            x: seed[0],
+            w: seed[1],
+        };
+
+        init_msws(&mut msws);
+
+        Ok(msws)
     }
 
     fn from_seed(seed: u64) -> MiddleSquaresWeylSequence {
         msws_from_split_mix(&mut SplitMix::from_seed(seed))
     }
+
+    /// Sets `x` and the Weyl sequence `w` directly, without the warm up calls [`RandomNumberInit::from_seed`] makes.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<MiddleSquaresWeylSequence, SeedError> {
+        let mut state = [0; 2];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(MiddleSquaresWeylSequence {
+            x: state[0],
+            w: state[1],
+        })
+    }
+}
+
+impl RandomNumberState for MiddleSquaresWeylSequence {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::MIDDLE_SQUARE_WEYL_SEQUENCE);
+        writer.write_u64(self.x);
+        writer.write_u64(self.w);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<MiddleSquaresWeylSequence, StateError> {
+        let mut reader = StateReader::new(state, tags::MIDDLE_SQUARE_WEYL_SEQUENCE)?;
+        let x = reader.read_u64()?;
+        let w = reader.read_u64()?;
+
+        reader.finish()?;
+
+        Ok(MiddleSquaresWeylSequence { x, w })
+    }
 }
 
 impl RandomNumber for MiddleSquaresWeylSequence {
@@ -68,6 +129,10 @@ impl RandomNumber for MiddleSquaresWeylSequence {
 
         (((xx ^ self.x) >> 11) as f64) * crate::TWO_53_INVERSE
     }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
 }
 
 fn msws_from_split_mix(split_mix: &mut SplitMix) -> MiddleSquaresWeylSequence {
@@ -138,4 +203,33 @@ mod tests {
 
         assert_eq!(msws.next_f64(), 0.250060447322643);
     }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let msws = MiddleSquaresWeylSequence::from_seed_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+
+        assert_eq!(msws.x, 1);
+        assert_eq!(msws.w, 2);
+        assert!(MiddleSquaresWeylSequence::from_seed_bytes(&[0; 15]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut msws = MiddleSquaresWeylSequence::from_seed(1);
+
+        for _ in 0..3 {
+            msws.next_u64();
+        }
+
+        let state = msws.save_state();
+        let mut restored = MiddleSquaresWeylSequence::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), msws.next_u64());
+        }
+
+        assert_eq!(msws.snapshot(), Some(restored.save_state()));
+        assert!(MiddleSquaresWeylSequence::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(MiddleSquaresWeylSequence::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
 }
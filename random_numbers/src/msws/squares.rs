@@ -0,0 +1,464 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    pub const KEY_DIGITS: usize = 8; // hex digits in each half of a key
+    pub const HEX_DIGITS: u64 = 15; // non-zero hex digits
+}
+
+/// Widynski's [Squares](https://arxiv.org/abs/2004.06278) counter-based generator with 32 bits of output: four
+/// rounds of the middle square Weyl sequence applied to `counter * key`.
+///
+/// Any position in the stream can be computed directly with [`Squares32::at`], so e.g. each element of a large
+/// array can get its own random number without the elements depending on each other.  Used sequentially, each
+/// call moves the counter on by one.
+#[derive(Debug, Clone)]
+pub struct Squares32 {
+    key: u64,
+    counter: u64
+}
+
+/// Widynski's [Squares](https://arxiv.org/abs/2004.06278) counter-based generator with 64 bits of output: five
+/// rounds of the middle square Weyl sequence applied to `counter * key`.  See [`Squares32`].
+#[derive(Debug, Clone)]
+pub struct Squares64 {
+    key: u64,
+    counter: u64
+}
+
+impl Squares32 {
+    /// Starts at counter zero with the given key.  The key should come from [`Squares32::generate_key`], an
+    /// arbitrary key can give poor output (a key of zero only gives zeros).
+    pub fn from_key(key: u64) -> Squares32 {
+        Squares32 { key, counter: 0 }
+    }
+
+    /// Generates a key using the procedure from the paper, see [`Squares64::generate_key`].
+    pub fn generate_key<R: RandomNumber + ?Sized>(rand: &mut R) -> u64 {
+        generate_key(rand)
+    }
+
+    /// Returns the output at `counter`, without changing the generator's own counter.
+    pub fn at(&self, counter: u64) -> u32 {
+        squares32(counter, self.key)
+    }
+
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// The counter the next call to [`RandomNumber::next_u32`] will use.
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+}
+
+impl Squares64 {
+    /// Starts at counter zero with the given key.  The key should come from [`Squares64::generate_key`], an
+    /// arbitrary key can give poor output (a key of zero only gives zeros).
+    pub fn from_key(key: u64) -> Squares64 {
+        Squares64 { key, counter: 0 }
+    }
+
+    /// Generates a key using the procedure from the paper: 16 non-zero hex digits, none repeated within the upper
+    /// or the lower 8, with neighbouring digits always different.  The lowest digit is odd, so every counter gives
+    /// a different `counter * key`.
+    pub fn generate_key<R: RandomNumber + ?Sized>(rand: &mut R) -> u64 {
+        generate_key(rand)
+    }
+
+    /// Returns the output at `counter`, without changing the generator's own counter.
+    pub fn at(&self, counter: u64) -> u64 {
+        squares64(counter, self.key)
+    }
+
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// The counter the next call to [`RandomNumber::next_u64`] will use.
+    pub fn counter(&self) -> u64 {
+        self.counter
+    }
+}
+
+impl RandomNumberInit for Squares32 {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Squares32> {
+        let mut seed = [0];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(Squares32::from_seed(seed[0]))
+    }
+
+    /// Generates a key with [`Squares32::generate_key`], starting at counter zero.
+    fn from_seed(seed: u64) -> Squares32 {
+        Squares32::from_key(generate_key(&mut SplitMix::from_seed(seed)))
+    }
+
+    /// The first 8 bytes are the key, the last 8 the counter.  A key of zero is rejected.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Squares32, SeedError> {
+        let (key, counter) = key_and_counter_from_seed_bytes(bytes)?;
+
+        Ok(Squares32 { key, counter })
+    }
+}
+
+impl RandomNumberInit for Squares64 {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Squares64> {
+        let mut seed = [0];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(Squares64::from_seed(seed[0]))
+    }
+
+    /// Generates a key with [`Squares64::generate_key`], starting at counter zero.
+    fn from_seed(seed: u64) -> Squares64 {
+        Squares64::from_key(generate_key(&mut SplitMix::from_seed(seed)))
+    }
+
+    /// The first 8 bytes are the key, the last 8 the counter.  A key of zero is rejected.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Squares64, SeedError> {
+        let (key, counter) = key_and_counter_from_seed_bytes(bytes)?;
+
+        Ok(Squares64 { key, counter })
+    }
+}
+
+impl RandomNumberState for Squares32 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::SQUARES32);
+        writer.write_u64(self.key);
+        writer.write_u64(self.counter);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Squares32, StateError> {
+        let (key, counter) = restore_key_and_counter(state, tags::SQUARES32)?;
+
+        Ok(Squares32 { key, counter })
+    }
+}
+
+impl RandomNumberState for Squares64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::SQUARES64);
+        writer.write_u64(self.key);
+        writer.write_u64(self.counter);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Squares64, StateError> {
+        let (key, counter) = restore_key_and_counter(state, tags::SQUARES64)?;
+
+        Ok(Squares64 { key, counter })
+    }
+}
+
+impl RandomNumber for Squares32 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let n = self.at(self.counter);
+
+        self.counter = self.counter.wrapping_add(1);
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumber for Squares64 {
+    fn next_u64(&mut self) -> u64 {
+        let n = self.at(self.counter);
+
+        self.counter = self.counter.wrapping_add(1);
+
+        n
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Squares32 {
+    /// Each step produces one [`RandomNumber::next_u32`].  The period is 2^64.
+    fn advance(&mut self, delta: u128) {
+        self.counter = self.counter.wrapping_add(delta as u64);
+    }
+}
+
+impl RandomNumberAdvance for Squares64 {
+    /// The period is 2^64.
+    fn advance(&mut self, delta: u128) {
+        self.counter = self.counter.wrapping_add(delta as u64);
+    }
+}
+
+fn generate_key<R: RandomNumber + ?Sized>(rand: &mut R) -> u64 {
+    let mut key = 0;
+    let mut previous = 0;
+
+    for half in 0..2 {
+        let mut used = 0u16;
+
+        for position in 0..constants::KEY_DIGITS {
+            let lowest = half == 1 && position == constants::KEY_DIGITS - 1;
+
+            // the previous digit is one of the seven already used in this half, so at least one of the eight odd
+            // digits is always left for the lowest digit
+            let digit = loop {
+                let digit = rand.next_u64_below(constants::HEX_DIGITS) + 1;
+
+                if used & (1 << digit) == 0 && digit != previous && (!lowest || digit % 2 == 1) {
+                    break digit;
+                }
+            };
+
+            used |= 1 << digit;
+            previous = digit;
+            key = key << 4 | digit;
+        }
+    }
+
+    key
+}
+
+fn key_and_counter_from_seed_bytes(bytes: &[u8]) -> Result<(u64, u64), SeedError> {
+    let mut seed = [0; 2];
+
+    seeds::u64s_from_seed_bytes(bytes, &mut seed)?;
+
+    // a zero key only produces zeros
+    if seed[0] == 0 {
+        return Err(SeedError::AllZero);
+    }
+
+    Ok((seed[0], seed[1]))
+}
+
+fn restore_key_and_counter(state: &[u8], tag: u8) -> Result<(u64, u64), StateError> {
+    let mut reader = StateReader::new(state, tag)?;
+    let key = reader.read_u64()?;
+    let counter = reader.read_u64()?;
+
+    reader.finish()?;
+
+    if key == 0 {
+        return Err(StateError::InvalidState);
+    }
+
+    Ok((key, counter))
+}
+
+// one round of the middle square Weyl sequence, with the Weyl sequence replaced by y or z
+fn round(x: u64, weyl: u64) -> u64 {
+    x.wrapping_mul(x).wrapping_add(weyl).rotate_left(32)
+}
+
+fn squares32(counter: u64, key: u64) -> u32 {
+    let y = counter.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+
+    let mut x = round(y, y);
+    x = round(x, z);
+    x = round(x, y);
+
+    (x.wrapping_mul(x).wrapping_add(z) >> 32) as u32
+}
+
+fn squares64(counter: u64, key: u64) -> u64 {
+    let y = counter.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+
+    let mut x = round(y, y);
+    x = round(x, z);
+    x = round(x, y);
+
+    let t = x.wrapping_mul(x).wrapping_add(z);
+    x = t.rotate_left(32);
+
+    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // computed with the squares32 and squares64 listings from the paper
+    const KEY: u64 = 0x548c9decbce65297;
+    const COUNTERS: [u64; 5] = [0, 1, 2, 1000, u64::MAX];
+
+    #[test]
+    fn new_test() {
+        let mut squares = Squares32::new();
+
+        squares.next_u64();
+
+        let mut squares = Squares64::new();
+
+        squares.next_u64();
+    }
+
+    #[test]
+    fn squares32_reference_test() {
+        let squares = Squares32::from_key(KEY);
+
+        let expected: [u32; 5] = [920159078, 2487686880, 3366515936, 3579893622, 1128597156];
+
+        for (counter, n) in COUNTERS.iter().zip(expected.iter()) {
+            assert_eq!(squares.at(*counter), *n);
+        }
+    }
+
+    #[test]
+    fn squares64_reference_test() {
+        let squares = Squares64::from_key(KEY);
+
+        let expected: [u64; 5] = [
+            3952053150598706085,
+            10684533792529506218,
+            14459075848319685823,
+            15375526030845584198,
+            4847287876544065568,
+        ];
+
+        for (counter, n) in COUNTERS.iter().zip(expected.iter()) {
+            assert_eq!(squares.at(*counter), *n);
+        }
+    }
+
+    #[test]
+    fn sequential_test() {
+        let mut squares = Squares32::from_key(KEY);
+
+        assert_eq!(squares.next_u32(), 920159078);
+        assert_eq!(squares.next_u64(), (2487686880u64 << 32) | 3366515936);
+        assert_eq!(squares.counter(), 3);
+
+        let mut squares = Squares64::from_key(KEY);
+
+        assert_eq!(squares.next_u64(), 3952053150598706085);
+        assert_eq!(squares.next_u64(), 10684533792529506218);
+        assert_eq!(squares.counter(), 2);
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut squares = Squares64::from_key(KEY);
+
+        squares.advance(1000);
+
+        assert_eq!(squares.next_u64(), 15375526030845584198);
+
+        // the counter wraps around
+        squares.advance(u128::MAX);
+
+        assert_eq!(squares.next_u64(), 15375526030845584198);
+    }
+
+    #[test]
+    fn generate_key_test() {
+        let mut rand = SplitMix::from_seed(0);
+
+        for _ in 0..1000 {
+            let key = Squares64::generate_key(&mut rand);
+            let digits: Vec<u64> = (0..16).rev().map(|i| (key >> (4 * i)) & 0xF).collect();
+
+            assert_eq!(key % 2, 1);
+            assert!(digits.iter().all(|digit| *digit != 0));
+            assert!(digits.windows(2).all(|pair| pair[0] != pair[1]));
+
+            for half in digits.chunks(8) {
+                let mut sorted = half.to_vec();
+                sorted.sort_unstable();
+                sorted.dedup();
+
+                assert_eq!(sorted.len(), 8);
+            }
+        }
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let squares = Squares32::from_seed(1);
+
+        assert_eq!(squares.key(), generate_key(&mut SplitMix::from_seed(1)));
+        assert_eq!(squares.counter(), 0);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = [0; 16];
+        bytes[0..8].copy_from_slice(&KEY.to_le_bytes());
+        bytes[8..16].copy_from_slice(&1000u64.to_le_bytes());
+
+        let mut squares = Squares64::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(squares.next_u64(), 15375526030845584198);
+        assert_eq!(Squares64::from_seed_bytes(&[0; 16]).unwrap_err(), SeedError::AllZero);
+        assert!(Squares32::from_seed_bytes(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut squares = Squares32::from_seed(1);
+
+        squares.next_u64();
+
+        let state = squares.save_state();
+        let mut restored = Squares32::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), squares.next_u32());
+        }
+
+        assert_eq!(squares.snapshot(), Some(restored.save_state()));
+        assert!(Squares32::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Squares64::restore_state(&state).is_err());
+
+        let state = Squares64::from_key(0).save_state();
+
+        assert_eq!(Squares64::restore_state(&state).unwrap_err(), StateError::InvalidState);
+    }
+}
@@ -0,0 +1,33 @@
+pub mod pcg32;
+pub mod pcg64;
+pub mod pcg64_dxsm;
+
+// Brown's "Random Number Generation with Arbitrary Strides": returns the state of the LCG
+// `state * multiplier + increment` after `delta` steps, in O(log delta) multiplications
+macro_rules! make_advance_lcg {
+    ($fn_name:ident, $t:ty) => {
+        pub(crate) fn $fn_name(state: $t, delta: $t, multiplier: $t, increment: $t) -> $t {
+            let mut delta = delta;
+            let mut current_multiplier = multiplier;
+            let mut current_increment = increment;
+            let mut accumulated_multiplier: $t = 1;
+            let mut accumulated_increment: $t = 0;
+
+            while delta > 0 {
+                if delta & 1 == 1 {
+                    accumulated_multiplier = accumulated_multiplier.wrapping_mul(current_multiplier);
+                    accumulated_increment = accumulated_increment.wrapping_mul(current_multiplier).wrapping_add(current_increment);
+                }
+
+                current_increment = current_multiplier.wrapping_add(1).wrapping_mul(current_increment);
+                current_multiplier = current_multiplier.wrapping_mul(current_multiplier);
+                delta >>= 1;
+            }
+
+            accumulated_multiplier.wrapping_mul(state).wrapping_add(accumulated_increment)
+        }
+    };
+}
+
+make_advance_lcg!(advance_lcg_64, u64);
+make_advance_lcg!(advance_lcg_128, u128);
@@ -0,0 +1,252 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::RandomNumberAdvance;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    pub const MULTIPLIER: u64 = 6364136223846793005;
+}
+
+/// PCG-XSH-RR with 64 bits of state and 32 bits of output, i.e. `pcg32_random_r` from the reference pcg-c library.
+#[derive(Debug)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64
+}
+
+impl RandomNumberInit for Pcg32 {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Pcg32> {
+        let mut seed = [0; 2];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(pcg32_from_state_and_stream(seed[0], seed[1]))
+    }
+
+    fn from_seed(seed: u64) -> Pcg32 {
+        pcg32_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// The first 8 bytes are the initial state, the last 8 select the stream.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Pcg32, SeedError> {
+        let mut seed = [0; 2];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut seed)?;
+
+        Ok(pcg32_from_state_and_stream(seed[0], seed[1]))
+    }
+}
+
+impl RandomNumberState for Pcg32 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::PCG32);
+        writer.write_u64(self.state);
+        writer.write_u64(self.increment);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Pcg32, StateError> {
+        let mut reader = StateReader::new(state, tags::PCG32)?;
+        let pcg = Pcg32 {
+            state: reader.read_u64()?,
+            increment: reader.read_u64()?,
+        };
+
+        reader.finish()?;
+
+        // the increment is always odd
+        if pcg.increment & 1 == 0 {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(pcg)
+    }
+}
+
+impl RandomNumber for Pcg32 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+
+        step(self);
+
+        output_xsh_rr(old_state)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Pcg32 {
+    /// Each step produces one [`RandomNumber::next_u32`].  The period is 2^64.
+    fn advance(&mut self, delta: u128) {
+        self.state = super::advance_lcg_64(self.state, delta as u64, constants::MULTIPLIER, self.increment);
+    }
+}
+
+fn pcg32_from_split_mix(split_mix: &mut SplitMix) -> Pcg32 {
+    pcg32_from_state_and_stream(split_mix.next_u64(), split_mix.next_u64())
+}
+
+// matches pcg32_srandom_r(rng, init_state, init_sequence) from pcg-c
+fn pcg32_from_state_and_stream(init_state: u64, init_sequence: u64) -> Pcg32 {
+    let mut pcg = Pcg32 {
+        state: 0,
+        // the increment must be odd, so the highest bit of the sequence is discarded
+        increment: (init_sequence << 1) | 1,
+    };
+
+    step(&mut pcg);
+    pcg.state = pcg.state.wrapping_add(init_state);
+    step(&mut pcg);
+
+    pcg
+}
+
+fn step(pcg: &mut Pcg32) {
+    pcg.state = pcg.state.wrapping_mul(constants::MULTIPLIER).wrapping_add(pcg.increment);
+}
+
+fn output_xsh_rr(state: u64) -> u32 {
+    let xor_shifted = (((state >> 18) ^ state) >> 27) as u32;
+    let rot = (state >> 59) as u32;
+
+    xor_shifted.rotate_right(rot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut pcg = Pcg32::new();
+
+        pcg.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let pcg = Pcg32::from_seed(0);
+
+        assert_eq!(pcg.increment % 2, 1);
+        assert_eq!(pcg.increment, (16294208416658607535 << 1) | 1);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut pcg = Pcg32::from_seed(0);
+
+        assert_eq!(pcg.next_u32(), 2489713799);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut pcg = Pcg32::from_seed(0);
+
+        assert_eq!(pcg.next_u64(), 10693239347013982459);
+    }
+
+    #[test]
+    fn pcg_c_reference_test() {
+        // first six outputs of pcg32-demo from pcg-c, seeded with state 42 and sequence 54
+        let mut pcg = pcg32_from_state_and_stream(42, 54);
+
+        let expected: [u32; 6] = [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e];
+
+        for e in expected.iter() {
+            assert_eq!(pcg.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = 42u64.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&54u64.to_le_bytes());
+
+        let mut pcg = Pcg32::from_seed_bytes(&bytes).unwrap();
+        let mut expected = pcg32_from_state_and_stream(42, 54);
+
+        assert_eq!(pcg.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut pcg = Pcg32::from_seed(1);
+
+        for _ in 0..3 {
+            pcg.next_u64();
+        }
+
+        let state = pcg.save_state();
+        let mut restored = Pcg32::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), pcg.next_u64());
+        }
+
+        assert_eq!(pcg.snapshot(), Some(restored.save_state()));
+        assert!(Pcg32::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Pcg32::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut pcg = Pcg32::from_seed(1);
+        let mut expected = Pcg32::from_seed(1);
+
+        for delta in [0, 1, 2, 7, 1000] {
+            pcg.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u32();
+            }
+
+            assert_eq!(pcg.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut pcg = Pcg32::from_seed(1);
+        let mut expected = Pcg32::from_seed(1);
+
+        // the period is 2^64, so going back one step is the same as advancing by the period - 1
+        pcg.next_u32();
+        pcg.advance(u128::MAX);
+
+        assert_eq!(pcg.next_u32(), expected.next_u32());
+    }
+}
@@ -0,0 +1,240 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::RandomNumberAdvance;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    pub const MULTIPLIER: u128 = 0x2360ED051FC65DA44385DF649FCCF645;
+}
+
+/// PCG-XSL-RR with 128 bits of state and 64 bits of output, i.e. `pcg64_random_r` from the reference pcg-c library.
+#[derive(Debug)]
+pub struct Pcg64 {
+    state: u128,
+    increment: u128
+}
+
+impl RandomNumberInit for Pcg64 {
+    const SEED_BYTES: usize = 32;
+
+    fn try_new() -> io::Result<Pcg64> {
+        let mut seed = [0; 4];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        let state = (seed[0] as u128) << 64 | (seed[1] as u128);
+        let stream = (seed[2] as u128) << 64 | (seed[3] as u128);
+
+        Ok(pcg64_from_state_and_stream(state, stream))
+    }
+
+    fn from_seed(seed: u64) -> Pcg64 {
+        pcg64_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// The first 16 bytes are the initial state, the last 16 select the stream.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Pcg64, SeedError> {
+        let mut seed = [0; 4];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut seed)?;
+
+        let state = (seed[1] as u128) << 64 | (seed[0] as u128);
+        let stream = (seed[3] as u128) << 64 | (seed[2] as u128);
+
+        Ok(pcg64_from_state_and_stream(state, stream))
+    }
+}
+
+impl RandomNumberState for Pcg64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::PCG64);
+        writer.write_u128(self.state);
+        writer.write_u128(self.increment);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Pcg64, StateError> {
+        let mut reader = StateReader::new(state, tags::PCG64)?;
+        let pcg = Pcg64 {
+            state: reader.read_u128()?,
+            increment: reader.read_u128()?,
+        };
+
+        reader.finish()?;
+
+        // the increment is always odd
+        if pcg.increment & 1 == 0 {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(pcg)
+    }
+}
+
+impl RandomNumber for Pcg64 {
+    fn next_u64(&mut self) -> u64 {
+        step(self);
+
+        output_xsl_rr(self.state)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Pcg64 {
+    fn advance(&mut self, delta: u128) {
+        self.state = super::advance_lcg_128(self.state, delta, constants::MULTIPLIER, self.increment);
+    }
+}
+
+fn pcg64_from_split_mix(split_mix: &mut SplitMix) -> Pcg64 {
+    pcg64_from_state_and_stream(split_mix.next_u128(), split_mix.next_u128())
+}
+
+// matches pcg64_srandom_r(rng, init_state, init_sequence) from pcg-c
+fn pcg64_from_state_and_stream(init_state: u128, init_sequence: u128) -> Pcg64 {
+    let mut pcg = Pcg64 {
+        state: 0,
+        // the increment must be odd, so the highest bit of the sequence is discarded
+        increment: (init_sequence << 1) | 1,
+    };
+
+    step(&mut pcg);
+    pcg.state = pcg.state.wrapping_add(init_state);
+    step(&mut pcg);
+
+    pcg
+}
+
+fn step(pcg: &mut Pcg64) {
+    pcg.state = pcg.state.wrapping_mul(constants::MULTIPLIER).wrapping_add(pcg.increment);
+}
+
+fn output_xsl_rr(state: u128) -> u64 {
+    let rot = (state >> 122) as u32;
+
+    (((state >> 64) as u64) ^ (state as u64)).rotate_right(rot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut pcg = Pcg64::new();
+
+        pcg.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let pcg = Pcg64::from_seed(0);
+
+        assert_eq!(pcg.increment % 2, 1);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut pcg = Pcg64::from_seed(0);
+
+        assert_eq!(pcg.next_u32(), 3170623556);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut pcg = Pcg64::from_seed(0);
+
+        assert_eq!(pcg.next_u64(), 13617724484963804352);
+    }
+
+    #[test]
+    fn pcg_c_reference_test() {
+        // first six outputs of pcg64-demo from pcg-c, seeded with state 42 and sequence 54
+        let mut pcg = pcg64_from_state_and_stream(42, 54);
+
+        let expected: [u64; 6] = [
+            0x86b1da1d72062b68,
+            0x1304aa46c9853d39,
+            0xa3670e9e0dd50358,
+            0xf9090e529a7dae00,
+            0xc85b9fd837996f2c,
+            0x606121f8e3919196,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(pcg.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = 42u128.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&54u128.to_le_bytes());
+
+        let mut pcg = Pcg64::from_seed_bytes(&bytes).unwrap();
+        let mut expected = pcg64_from_state_and_stream(42, 54);
+
+        assert_eq!(pcg.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut pcg = Pcg64::from_seed(1);
+
+        for _ in 0..3 {
+            pcg.next_u64();
+        }
+
+        let state = pcg.save_state();
+        let mut restored = Pcg64::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), pcg.next_u64());
+        }
+
+        assert_eq!(pcg.snapshot(), Some(restored.save_state()));
+        assert!(Pcg64::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Pcg64::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut pcg = Pcg64::from_seed(1);
+        let mut expected = Pcg64::from_seed(1);
+
+        for delta in [0, 1, 2, 7, 1000] {
+            pcg.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(pcg.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut pcg = Pcg64::from_seed(1);
+        let mut expected = Pcg64::from_seed(1);
+
+        // the period is 2^128, so going back one step is the same as advancing by the period - 1
+        pcg.next_u64();
+        pcg.advance(u128::MAX);
+
+        assert_eq!(pcg.next_u64(), expected.next_u64());
+    }
+}
@@ -0,0 +1,325 @@
+//! Interoperability with the [`rand_core`] traits, enabled by the `rand_core` feature.
+//!
+//! Every generator implements [`RngCore`] and [`SeedableRng`], so it can be passed to crates built on `rand`.
+//! [`RngCoreAdapter`] goes the other way, wrapping any [`RngCore`] as a [`RandomNumber`].
+//!
+//! Both `rand_core` and this library name their methods `next_u32`, `next_u64`, `fill_bytes` and `from_seed`, so
+//! when both traits are in scope you'll need to call them as e.g. `RandomNumber::next_u64(&mut rand)`.
+
+use std::fmt;
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::chacha::{ChaCha, ChaChaRounds, ValidRounds};
+use crate::jsf::Jsf64;
+use crate::mcg::lehmer64::Lehmer64;
+use crate::mcg::mcg128::Mcg128;
+use crate::mersenne_twister::MersenneTwister;
+use crate::mersenne_twister::legacy::MersenneTwisterLegacy;
+use crate::mersenne_twister::mt19937::MT19937;
+use crate::mersenne_twister::sfmt::Sfmt19937;
+use crate::msws::MiddleSquaresWeylSequence;
+use crate::msws::squares::{Squares32, Squares64};
+use crate::pcg::pcg32::Pcg32;
+use crate::pcg::pcg64::Pcg64;
+use crate::pcg::pcg64_dxsm::Pcg64Dxsm;
+use crate::random123::philox::Philox4x32;
+use crate::random123::threefry::Threefry4x64;
+use crate::romu::romu_duo_jr::RomuDuoJr;
+use crate::romu::romu_trio::RomuTrio;
+use crate::sfc::Sfc64;
+use crate::split_mix::SplitMix;
+use crate::well::well1024a::Well1024a;
+use crate::well::well512a::Well512a;
+use crate::wyrand::WyRand;
+use crate::xorshift::xoroshiro_128p::Xoroshiro128P;
+use crate::xorshift::xoroshiro_128pp::Xoroshiro128PP;
+use crate::xorshift::xoroshiro_128ss::Xoroshiro128SS;
+use crate::xorshift::xorshift_plus::XorshiftPlus;
+use crate::xorshift::xoshiro_128pp::Xoshiro128PP;
+use crate::xorshift::xoshiro_128ss::Xoshiro128SS;
+use crate::xorshift::xoshiro_256p::Xoshiro256P;
+use crate::xorshift::xoshiro_256pp::Xoshiro256PP;
+use crate::xorshift::xoshiro_256ss::Xoshiro256SS;
+use crate::xorshift::xoshiro_512ss::Xoshiro512SS;
+
+/// A [`SeedableRng::Seed`] for generators whose state is larger than 32 bytes.  The standard library only
+/// implements [`Default`] for arrays of up to 32 elements.
+#[derive(Clone)]
+pub struct LargeSeed<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Default for LargeSeed<N> {
+    fn default() -> LargeSeed<N> {
+        LargeSeed([0; N])
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for LargeSeed<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for LargeSeed<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+// the seed is the generator's entire state, see RandomNumberInit::from_seed_bytes.  Seeds the generator can't
+// use (all zeros for the xorshift family) fall back to from_seed(0), like the rand_xoshiro crate does, except
+// even seeds for the MCGs, which get their lowest bit set like the rand_pcg crate does.
+fn from_seed_bytes_or_zero<R: RandomNumberInit>(seed: &[u8]) -> R {
+    match R::from_seed_bytes(seed) {
+        Ok(generator) => generator,
+        Err(SeedError::Even) => {
+            let mut odd_seed = seed.to_vec();
+
+            odd_seed[0] |= 1;
+            from_seed_bytes_or_zero(&odd_seed)
+        },
+        Err(_) => <R as RandomNumberInit>::from_seed(0),
+    }
+}
+
+macro_rules! impl_rand_core {
+    ($generator:ty, $seed:ty) => {
+        impl RngCore for $generator {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                RandomNumber::next_u32(self)
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                RandomNumber::next_u64(self)
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+
+        impl SeedableRng for $generator {
+            type Seed = $seed;
+
+            fn from_seed(seed: $seed) -> $generator {
+                from_seed_bytes_or_zero(seed.as_ref())
+            }
+
+            /// Matches [`RandomNumberInit::from_seed`], so a `u64` seed gives the same sequence through either trait.
+            fn seed_from_u64(state: u64) -> $generator {
+                <$generator as RandomNumberInit>::from_seed(state)
+            }
+        }
+    };
+}
+
+impl_rand_core!(Jsf64, [u8; 32]);
+impl_rand_core!(Lehmer64, [u8; 16]);
+impl_rand_core!(Mcg128, [u8; 16]);
+impl_rand_core!(MersenneTwister, LargeSeed<{ MersenneTwister::SEED_BYTES }>);
+impl_rand_core!(MersenneTwisterLegacy, LargeSeed<{ MersenneTwisterLegacy::SEED_BYTES }>);
+impl_rand_core!(MT19937, LargeSeed<{ MT19937::SEED_BYTES }>);
+impl_rand_core!(MiddleSquaresWeylSequence, [u8; 16]);
+impl_rand_core!(Pcg32, [u8; 16]);
+impl_rand_core!(Pcg64, [u8; 32]);
+impl_rand_core!(Pcg64Dxsm, [u8; 32]);
+impl_rand_core!(Philox4x32, [u8; 24]);
+impl_rand_core!(RomuDuoJr, [u8; 16]);
+impl_rand_core!(RomuTrio, [u8; 24]);
+impl_rand_core!(Sfc64, [u8; 32]);
+impl_rand_core!(Sfmt19937, LargeSeed<{ Sfmt19937::SEED_BYTES }>);
+impl_rand_core!(SplitMix, [u8; 8]);
+impl_rand_core!(Squares32, [u8; 16]);
+impl_rand_core!(Squares64, [u8; 16]);
+impl_rand_core!(Threefry4x64, LargeSeed<64>);
+impl_rand_core!(Well1024a, LargeSeed<128>);
+impl_rand_core!(Well512a, LargeSeed<64>);
+impl_rand_core!(WyRand, [u8; 8]);
+impl_rand_core!(Xoroshiro128P, [u8; 16]);
+impl_rand_core!(Xoroshiro128PP, [u8; 16]);
+impl_rand_core!(Xoroshiro128SS, [u8; 16]);
+impl_rand_core!(XorshiftPlus, [u8; 16]);
+impl_rand_core!(Xoshiro128PP, [u8; 16]);
+impl_rand_core!(Xoshiro128SS, [u8; 16]);
+impl_rand_core!(Xoshiro256P, [u8; 32]);
+impl_rand_core!(Xoshiro256PP, [u8; 32]);
+impl_rand_core!(Xoshiro256SS, [u8; 32]);
+impl_rand_core!(Xoshiro512SS, LargeSeed<64>);
+
+impl<const ROUNDS: usize> RngCore for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        RandomNumber::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        RandomNumber::next_u64(self)
+    }
+
+    // keeps the keystream byte order, so the output matches other ChaCha implementations
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        RandomNumber::fill_bytes(self, dst)
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    type Seed = LargeSeed<40>;
+
+    fn from_seed(seed: LargeSeed<40>) -> ChaCha<ROUNDS> {
+        from_seed_bytes_or_zero(seed.as_ref())
+    }
+
+    fn seed_from_u64(state: u64) -> ChaCha<ROUNDS> {
+        <ChaCha<ROUNDS> as RandomNumberInit>::from_seed(state)
+    }
+}
+
+impl<const ROUNDS: usize> CryptoRng for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {}
+
+/// Wraps any [`RngCore`] as a [`RandomNumber`], so generators from the `rand` ecosystem can be used with this
+/// library, e.g. in `random_distributions`.
+///
+/// ```
+/// use random_numbers::prelude::*;
+/// use rand_core::SeedableRng;
+///
+/// let mut rand = RngCoreAdapter::new(rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(7));
+///
+/// let n = rand.next_f64();
+/// ```
+pub struct RngCoreAdapter<R: RngCore> {
+    rng: R
+}
+
+impl<R: RngCore> RngCoreAdapter<R> {
+    pub fn new(rng: R) -> RngCoreAdapter<R> {
+        RngCoreAdapter { rng }
+    }
+
+    /// Returns the wrapped generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+// RandomNumber requires Debug, but not every RngCore implements it
+impl<R: RngCore> fmt::Debug for RngCoreAdapter<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RngCoreAdapter {{ .. }}")
+    }
+}
+
+impl<R: RngCore> RandomNumber for RngCoreAdapter<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chacha::ChaCha20;
+
+    #[test]
+    fn rng_core_test() {
+        let mut rand = Xoshiro256SS::seed_from_u64(0);
+        let mut expected = <Xoshiro256SS as RandomNumberInit>::from_seed(0);
+
+        assert_eq!(RngCore::next_u64(&mut rand), RandomNumber::next_u64(&mut expected));
+        assert_eq!(RngCore::next_u32(&mut rand), RandomNumber::next_u32(&mut expected));
+    }
+
+    #[test]
+    fn fill_bytes_test() {
+        let mut rand = Pcg64::seed_from_u64(0);
+        let mut expected = <Pcg64 as RandomNumberInit>::from_seed(0);
+        let mut bytes = [0; 12];
+
+        RngCore::fill_bytes(&mut rand, &mut bytes);
+
+        assert_eq!(bytes[0..8], RandomNumber::next_u64(&mut expected).to_le_bytes());
+        assert_eq!(bytes[8..12], RandomNumber::next_u32(&mut expected).to_le_bytes());
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let mut seed = [0; 32];
+        seed[0] = 1;
+
+        let mut rand = <Xoshiro256SS as SeedableRng>::from_seed(seed);
+        let mut expected = Xoshiro256SS::from_seed_bytes(&seed).unwrap();
+
+        assert_eq!(RngCore::next_u64(&mut rand), RandomNumber::next_u64(&mut expected));
+    }
+
+    #[test]
+    fn from_zero_seed_test() {
+        let mut rand = <XorshiftPlus as SeedableRng>::from_seed([0; 16]);
+        let mut expected = <XorshiftPlus as RandomNumberInit>::from_seed(0);
+
+        assert_eq!(RngCore::next_u64(&mut rand), RandomNumber::next_u64(&mut expected));
+    }
+
+    #[test]
+    fn from_even_seed_test() {
+        let mut seed = [0; 16];
+        seed[0] = 2;
+
+        let mut rand = <Lehmer64 as SeedableRng>::from_seed(seed);
+        seed[0] = 3;
+        let mut expected = Lehmer64::from_seed_bytes(&seed).unwrap();
+
+        assert_eq!(RngCore::next_u64(&mut rand), RandomNumber::next_u64(&mut expected));
+    }
+
+    #[test]
+    fn large_seed_test() {
+        let mut rand = MersenneTwister::from_rng(&mut SplitMix::seed_from_u64(1));
+
+        RngCore::next_u64(&mut rand);
+    }
+
+    #[test]
+    fn chacha_fill_bytes_test() {
+        let mut rand = ChaCha20::seed_from_u64(0);
+        let mut expected = <ChaCha20 as RandomNumberInit>::from_seed(0);
+        let mut bytes = [0; 7];
+
+        RngCore::fill_bytes(&mut rand, &mut bytes);
+
+        assert_eq!(bytes[0..4], RandomNumber::next_u32(&mut expected).to_le_bytes());
+        assert_eq!(bytes[4..7], RandomNumber::next_u32(&mut expected).to_le_bytes()[0..3]);
+    }
+
+    #[test]
+    fn rng_core_adapter_test() {
+        // rand_xoshiro's Xoshiro256StarStar is the same algorithm, so the same state gives the same output
+        let seed = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+
+        let mut rand = RngCoreAdapter::new(rand_xoshiro::Xoshiro256StarStar::from_seed(seed));
+        let mut expected = Xoshiro256SS::from_seed_bytes(&seed).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(rand.next_u64(), RandomNumber::next_u64(&mut expected));
+        }
+
+        let mut boxed: Box<dyn RandomNumber> = Box::new(rand);
+        boxed.next_f64();
+    }
+}
@@ -0,0 +1,6 @@
+pub mod philox;
+pub mod threefry;
+
+// Counter-based generators from Random123 (Salmon et al., "Parallel Random Numbers: As Easy as 1, 2, 3").  Each
+// block of output is a pure function of the key and a counter.  See https://github.com/DEShawResearch/random123
+// for the reference implementations and known-answer tests.
@@ -0,0 +1,341 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    pub const M0: u64 = 0xD2511F53;
+    pub const M1: u64 = 0xCD9E8D57;
+
+    // the Weyl sequence increments for the key, the golden ratio and sqrt(3) - 1
+    pub const W0: u32 = 0x9E3779B9;
+    pub const W1: u32 = 0xBB67AE85;
+
+    pub const ROUNDS: usize = 10;
+    pub const BLOCK_WORDS: usize = 4;
+}
+
+/// Philox4x32-10 from Random123: a 64-bit key and a 128-bit counter, producing a block of four 32-bit words per
+/// counter value with 10 rounds of multiplication and xor.
+///
+/// [`Philox4x32::block`] computes the block for any counter, so e.g. each simulation entity can derive its random
+/// numbers from its ID.  Used sequentially, the words of each block are returned in order and then the counter is
+/// incremented.  The counter's first word is the least significant.
+#[derive(Debug, Clone)]
+pub struct Philox4x32 {
+    key: [u32; 2],
+    counter: [u32; 4],
+    block: [u32; constants::BLOCK_WORDS],
+    index: usize
+}
+
+impl Philox4x32 {
+    /// Starts at counter zero with the given key.
+    pub fn from_key(key: [u32; 2]) -> Philox4x32 {
+        Philox4x32 {
+            key,
+            counter: [0; 4],
+            block: [0; constants::BLOCK_WORDS],
+            // forces a new block to be generated on the first call
+            index: constants::BLOCK_WORDS,
+        }
+    }
+
+    /// Returns the block at `counter`, without changing the generator's own counter.
+    pub fn block(&self, counter: [u32; 4]) -> [u32; 4] {
+        philox4x32(self.key, counter)
+    }
+
+    /// Returns the block at the generator's counter, and increments the counter.  Any words buffered by
+    /// [`RandomNumber::next_u32`] are skipped.
+    pub fn next_block(&mut self) -> [u32; 4] {
+        let block = self.block(self.counter);
+
+        self.set_counter(add_to_counter(self.counter, 1));
+
+        block
+    }
+
+    pub fn key(&self) -> [u32; 2] {
+        self.key
+    }
+
+    /// The counter of the next block to be generated.
+    pub fn counter(&self) -> [u32; 4] {
+        self.counter
+    }
+
+    /// Moves the generator to `counter`, discarding any buffered words.
+    pub fn set_counter(&mut self, counter: [u32; 4]) {
+        self.counter = counter;
+        self.index = constants::BLOCK_WORDS;
+    }
+}
+
+impl RandomNumberInit for Philox4x32 {
+    const SEED_BYTES: usize = 24;
+
+    fn try_new() -> io::Result<Philox4x32> {
+        let mut key = [0; 2];
+
+        seeds::os_random_u32s(&mut key)?;
+
+        Ok(Philox4x32::from_key(key))
+    }
+
+    fn from_seed(seed: u64) -> Philox4x32 {
+        let n = SplitMix::from_seed(seed).next_u64();
+
+        Philox4x32::from_key([n as u32, (n >> 32) as u32])
+    }
+
+    /// The first 8 bytes are the key, the last 16 the counter.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Philox4x32, SeedError> {
+        let mut seed = [0; 6];
+
+        seeds::u32s_from_seed_bytes(bytes, &mut seed)?;
+
+        let mut philox = Philox4x32::from_key([seed[0], seed[1]]);
+        philox.counter.copy_from_slice(&seed[2..]);
+
+        Ok(philox)
+    }
+}
+
+impl RandomNumberState for Philox4x32 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::PHILOX4X32);
+        writer.write_u32s(&self.key);
+        writer.write_u32s(&self.counter);
+        writer.write_u64(self.index as u64);
+
+        writer.finish()
+    }
+
+    /// The buffered block isn't part of the saved state, it's regenerated from the key and counter.
+    fn restore_state(state: &[u8]) -> Result<Philox4x32, StateError> {
+        let mut reader = StateReader::new(state, tags::PHILOX4X32)?;
+        let mut key = [0; 2];
+        let mut counter = [0; 4];
+
+        reader.read_u32s(&mut key)?;
+        reader.read_u32s(&mut counter)?;
+        let index = reader.read_index(constants::BLOCK_WORDS)?;
+        reader.finish()?;
+
+        let mut philox = Philox4x32::from_key(key);
+        philox.counter = counter;
+
+        if index < constants::BLOCK_WORDS {
+            // the counter was incremented when the buffered block was generated
+            philox.counter = add_to_counter(counter, u128::MAX);
+            generate_block(&mut philox);
+            philox.index = index;
+        }
+
+        Ok(philox)
+    }
+}
+
+impl RandomNumber for Philox4x32 {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::BLOCK_WORDS {
+            generate_block(self);
+        }
+
+        let n = self.block[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Philox4x32 {
+    /// Each step produces one [`RandomNumber::next_u32`].  The period is 2^130, so `delta` never wraps.
+    fn advance(&mut self, delta: u128) {
+        // the counter of the block holding the next word, and the word's position in it
+        let (counter, index) = if self.index < constants::BLOCK_WORDS {
+            (add_to_counter(self.counter, u128::MAX), self.index)
+        } else {
+            (self.counter, 0)
+        };
+
+        let words = index as u128 + delta % constants::BLOCK_WORDS as u128;
+        let blocks = delta / constants::BLOCK_WORDS as u128 + words / constants::BLOCK_WORDS as u128;
+
+        self.set_counter(add_to_counter(counter, blocks));
+
+        let index = (words % constants::BLOCK_WORDS as u128) as usize;
+
+        if index > 0 {
+            generate_block(self);
+            self.index = index;
+        }
+    }
+}
+
+fn generate_block(philox: &mut Philox4x32) {
+    philox.block = philox.block(philox.counter);
+    philox.counter = add_to_counter(philox.counter, 1);
+    philox.index = 0;
+}
+
+// wraps around after 2^128 blocks
+fn add_to_counter(counter: [u32; 4], n: u128) -> [u32; 4] {
+    let sum = counter.iter().rev().fold(0, |sum, word| sum << 32 | *word as u128).wrapping_add(n);
+
+    [sum as u32, (sum >> 32) as u32, (sum >> 64) as u32, (sum >> 96) as u32]
+}
+
+fn philox4x32(mut key: [u32; 2], mut x: [u32; 4]) -> [u32; 4] {
+    for round in 0..constants::ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(constants::W0);
+            key[1] = key[1].wrapping_add(constants::W1);
+        }
+
+        let product0 = constants::M0 * x[0] as u64;
+        let product1 = constants::M1 * x[2] as u64;
+
+        x = [
+            ((product1 >> 32) as u32) ^ x[1] ^ key[0],
+            product1 as u32,
+            ((product0 >> 32) as u32) ^ x[3] ^ key[1],
+            product0 as u32,
+        ];
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut philox = Philox4x32::new();
+
+        philox.next_u64();
+    }
+
+    // the philox4x32 10 known-answer tests from Random123's kat_vectors
+    #[test]
+    fn random123_kat_test() {
+        assert_eq!(Philox4x32::from_key([0; 2]).block([0; 4]), [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]);
+
+        assert_eq!(
+            Philox4x32::from_key([0xffffffff; 2]).block([0xffffffff; 4]),
+            [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+        );
+
+        assert_eq!(
+            Philox4x32::from_key([0xa4093822, 0x299f31d0]).block([0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]),
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+        );
+    }
+
+    #[test]
+    fn sequential_test() {
+        let mut philox = Philox4x32::from_key([0; 2]);
+
+        assert_eq!(philox.next_u32(), 0x6627e8d5);
+        assert_eq!(philox.next_u64(), 0xe169c58d_bc57ac4c);
+        assert_eq!(philox.counter(), [1, 0, 0, 0]);
+
+        let block = philox.block([1, 0, 0, 0]);
+
+        assert_eq!(philox.next_u32(), 0x9b00dbd8);
+        assert_eq!(philox.next_u32(), block[0]);
+        assert_eq!(philox.next_block(), philox.block([2, 0, 0, 0]));
+        assert_eq!(philox.counter(), [3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn counter_carry_test() {
+        let mut philox = Philox4x32::from_key([1, 2]);
+        philox.set_counter([0xffffffff, 0xffffffff, 0, 0]);
+
+        philox.next_block();
+
+        assert_eq!(philox.counter(), [0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut philox = Philox4x32::from_seed(1);
+        let mut expected = Philox4x32::from_seed(1);
+
+        for delta in [0, 1, 2, 3, 4, 5, 7, 1000] {
+            philox.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u32();
+            }
+
+            assert_eq!(philox.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = [0; 24];
+        bytes[8] = 1;
+
+        let mut philox = Philox4x32::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(philox.next_u32(), Philox4x32::from_key([0; 2]).block([1, 0, 0, 0])[0]);
+        assert!(Philox4x32::from_seed_bytes(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut philox = Philox4x32::from_seed(1);
+
+        philox.next_u32();
+
+        let state = philox.save_state();
+        let mut restored = Philox4x32::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), philox.next_u32());
+        }
+
+        assert_eq!(philox.snapshot(), Some(restored.save_state()));
+        assert!(Philox4x32::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Philox4x32::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
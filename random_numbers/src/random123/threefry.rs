@@ -0,0 +1,372 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    // Threefish's key schedule parity constant, from the Skein hash
+    pub const PARITY: u64 = 0x1BD11BDAA9FC1A22;
+
+    pub const ROTATIONS: [[u32; 2]; 8] = [[14, 16], [52, 57], [23, 40], [5, 37], [25, 33], [46, 12], [58, 22], [32, 32]];
+
+    pub const ROUNDS: usize = 20;
+    pub const ROUNDS_PER_KEY: usize = 4; // rounds between each key injection
+    pub const BLOCK_WORDS: usize = 4;
+}
+
+/// Threefry4x64-20 from Random123: a 256-bit key and a 256-bit counter, producing a block of four 64-bit words per
+/// counter value with 20 rounds of the Threefish block cipher's add, rotate and xor.
+///
+/// [`Threefry4x64::block`] computes the block for any counter, so e.g. each simulation entity can derive its random
+/// numbers from its ID.  Used sequentially, the words of each block are returned in order and then the counter is
+/// incremented.  The counter's first word is the least significant.
+#[derive(Debug, Clone)]
+pub struct Threefry4x64 {
+    key: [u64; 4],
+    counter: [u64; 4],
+    block: [u64; constants::BLOCK_WORDS],
+    index: usize
+}
+
+impl Threefry4x64 {
+    /// Starts at counter zero with the given key.
+    pub fn from_key(key: [u64; 4]) -> Threefry4x64 {
+        Threefry4x64 {
+            key,
+            counter: [0; 4],
+            block: [0; constants::BLOCK_WORDS],
+            // forces a new block to be generated on the first call
+            index: constants::BLOCK_WORDS,
+        }
+    }
+
+    /// Returns the block at `counter`, without changing the generator's own counter.
+    pub fn block(&self, counter: [u64; 4]) -> [u64; 4] {
+        threefry4x64(self.key, counter)
+    }
+
+    /// Returns the block at the generator's counter, and increments the counter.  Any words buffered by
+    /// [`RandomNumber::next_u64`] are skipped.
+    pub fn next_block(&mut self) -> [u64; 4] {
+        let block = self.block(self.counter);
+
+        self.set_counter(add_to_counter(self.counter, 1));
+
+        block
+    }
+
+    pub fn key(&self) -> [u64; 4] {
+        self.key
+    }
+
+    /// The counter of the next block to be generated.
+    pub fn counter(&self) -> [u64; 4] {
+        self.counter
+    }
+
+    /// Moves the generator to `counter`, discarding any buffered words.
+    pub fn set_counter(&mut self, counter: [u64; 4]) {
+        self.counter = counter;
+        self.index = constants::BLOCK_WORDS;
+    }
+}
+
+impl RandomNumberInit for Threefry4x64 {
+    const SEED_BYTES: usize = 64;
+
+    fn try_new() -> io::Result<Threefry4x64> {
+        let mut key = [0; 4];
+
+        seeds::os_random_u64s(&mut key)?;
+
+        Ok(Threefry4x64::from_key(key))
+    }
+
+    fn from_seed(seed: u64) -> Threefry4x64 {
+        let mut split_mix = SplitMix::from_seed(seed);
+        let mut key = [0; 4];
+
+        for word in key.iter_mut() {
+            *word = split_mix.next_u64();
+        }
+
+        Threefry4x64::from_key(key)
+    }
+
+    /// The first 32 bytes are the key, the last 32 the counter.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Threefry4x64, SeedError> {
+        let mut seed = [0; 8];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut seed)?;
+
+        let mut key = [0; 4];
+        key.copy_from_slice(&seed[..4]);
+
+        let mut threefry = Threefry4x64::from_key(key);
+        threefry.counter.copy_from_slice(&seed[4..]);
+
+        Ok(threefry)
+    }
+}
+
+impl RandomNumberState for Threefry4x64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::THREEFRY4X64);
+        writer.write_u64s(&self.key);
+        writer.write_u64s(&self.counter);
+        writer.write_u64(self.index as u64);
+
+        writer.finish()
+    }
+
+    /// The buffered block isn't part of the saved state, it's regenerated from the key and counter.
+    fn restore_state(state: &[u8]) -> Result<Threefry4x64, StateError> {
+        let mut reader = StateReader::new(state, tags::THREEFRY4X64)?;
+        let mut key = [0; 4];
+        let mut counter = [0; 4];
+
+        reader.read_u64s(&mut key)?;
+        reader.read_u64s(&mut counter)?;
+        let index = reader.read_index(constants::BLOCK_WORDS)?;
+        reader.finish()?;
+
+        let mut threefry = Threefry4x64::from_key(key);
+        threefry.counter = counter;
+
+        if index < constants::BLOCK_WORDS {
+            // the counter was incremented when the buffered block was generated
+            threefry.counter = decrement_counter(counter);
+            generate_block(&mut threefry);
+            threefry.index = index;
+        }
+
+        Ok(threefry)
+    }
+}
+
+impl RandomNumber for Threefry4x64 {
+    fn next_u64(&mut self) -> u64 {
+        if self.index >= constants::BLOCK_WORDS {
+            generate_block(self);
+        }
+
+        let n = self.block[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for Threefry4x64 {
+    /// The period is 2^258, so `delta` never wraps.
+    fn advance(&mut self, delta: u128) {
+        // the counter of the block holding the next word, and the word's position in it
+        let (counter, index) = if self.index < constants::BLOCK_WORDS {
+            (decrement_counter(self.counter), self.index)
+        } else {
+            (self.counter, 0)
+        };
+
+        let words = index as u128 + delta % constants::BLOCK_WORDS as u128;
+        let blocks = delta / constants::BLOCK_WORDS as u128 + words / constants::BLOCK_WORDS as u128;
+
+        self.set_counter(add_to_counter(counter, blocks));
+
+        let index = (words % constants::BLOCK_WORDS as u128) as usize;
+
+        if index > 0 {
+            generate_block(self);
+            self.index = index;
+        }
+    }
+}
+
+fn generate_block(threefry: &mut Threefry4x64) {
+    threefry.block = threefry.block(threefry.counter);
+    threefry.counter = add_to_counter(threefry.counter, 1);
+    threefry.index = 0;
+}
+
+// wraps around after 2^256 blocks
+fn add_to_counter(mut counter: [u64; 4], n: u128) -> [u64; 4] {
+    let mut carry = n;
+
+    for word in counter.iter_mut() {
+        let sum = *word as u128 + (carry as u64) as u128;
+
+        *word = sum as u64;
+        carry = (carry >> 64) + (sum >> 64);
+
+        if carry == 0 {
+            break;
+        }
+    }
+
+    counter
+}
+
+fn decrement_counter(mut counter: [u64; 4]) -> [u64; 4] {
+    for word in counter.iter_mut() {
+        let borrow = *word == 0;
+
+        *word = word.wrapping_sub(1);
+
+        if !borrow {
+            break;
+        }
+    }
+
+    counter
+}
+
+fn threefry4x64(key: [u64; 4], counter: [u64; 4]) -> [u64; 4] {
+    let mut schedule = [0; 5];
+    schedule[..4].copy_from_slice(&key);
+    schedule[4] = key.iter().fold(constants::PARITY, |parity, word| parity ^ word);
+
+    let mut x = [0; 4];
+    for (i, word) in x.iter_mut().enumerate() {
+        *word = counter[i].wrapping_add(schedule[i]);
+    }
+
+    for round in 0..constants::ROUNDS {
+        let rotation = constants::ROTATIONS[round % constants::ROTATIONS.len()];
+
+        // alternates which pairs of words are mixed together
+        let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+
+        x[0] = x[0].wrapping_add(x[a]);
+        x[a] = x[a].rotate_left(rotation[0]) ^ x[0];
+
+        x[2] = x[2].wrapping_add(x[b]);
+        x[b] = x[b].rotate_left(rotation[1]) ^ x[2];
+
+        if round % constants::ROUNDS_PER_KEY == constants::ROUNDS_PER_KEY - 1 {
+            let injection = (round + 1) / constants::ROUNDS_PER_KEY;
+
+            for (i, word) in x.iter_mut().enumerate() {
+                *word = word.wrapping_add(schedule[(injection + i) % schedule.len()]);
+            }
+
+            x[3] = x[3].wrapping_add(injection as u64);
+        }
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut threefry = Threefry4x64::new();
+
+        threefry.next_u64();
+    }
+
+    // the threefry4x64 20 known-answer tests from Random123's kat_vectors
+    #[test]
+    fn random123_kat_test() {
+        assert_eq!(
+            Threefry4x64::from_key([0; 4]).block([0; 4]),
+            [0x09218ebde6c85537, 0x55941f5266d86105, 0x4bd25e16282434dc, 0xee29ec846bd2e40b]
+        );
+
+        assert_eq!(
+            Threefry4x64::from_key([u64::MAX; 4]).block([u64::MAX; 4]),
+            [0x29c24097942bba1b, 0x0371bbfb0f6f4e11, 0x3c231ffa33f83a1c, 0xcd29113fde32d168]
+        );
+
+        let key = [0x452821e638d01377, 0xbe5466cf34e90c6c, 0xbe5466cf34e90c6c, 0xc0ac29b7c97c50dd];
+        let counter = [0x243f6a8885a308d3, 0x13198a2e03707344, 0xa4093822299f31d0, 0x082efa98ec4e6c89];
+
+        assert_eq!(
+            Threefry4x64::from_key(key).block(counter),
+            [0xa7e8fde591651bd9, 0xbaafd0c30138319b, 0x84a5c1a729e685b9, 0x901d406ccebc1ba4]
+        );
+    }
+
+    #[test]
+    fn sequential_test() {
+        let mut threefry = Threefry4x64::from_key([0; 4]);
+
+        assert_eq!(threefry.next_u64(), 0x09218ebde6c85537);
+        assert_eq!(threefry.next_u64(), 0x55941f5266d86105);
+        assert_eq!(threefry.counter(), [1, 0, 0, 0]);
+
+        threefry.next_u64();
+        threefry.next_u64();
+
+        assert_eq!(threefry.next_u64(), threefry.block([1, 0, 0, 0])[0]);
+        assert_eq!(threefry.next_block(), threefry.block([2, 0, 0, 0]));
+        assert_eq!(threefry.counter(), [3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn counter_carry_test() {
+        assert_eq!(add_to_counter([u64::MAX, u64::MAX, 0, 0], 1), [0, 0, 1, 0]);
+        assert_eq!(add_to_counter([u64::MAX; 4], 1), [0; 4]);
+        assert_eq!(add_to_counter([1, 0, 0, 0], u128::MAX), [0, 0, 1, 0]);
+        assert_eq!(decrement_counter([0, 0, 1, 0]), [u64::MAX, u64::MAX, 0, 0]);
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut threefry = Threefry4x64::from_seed(1);
+        let mut expected = Threefry4x64::from_seed(1);
+
+        for delta in [0, 1, 2, 3, 4, 5, 7, 1000] {
+            threefry.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(threefry.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut bytes = [0; 64];
+        bytes[32] = 1;
+
+        let mut threefry = Threefry4x64::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(threefry.next_u64(), Threefry4x64::from_key([0; 4]).block([1, 0, 0, 0])[0]);
+        assert!(Threefry4x64::from_seed_bytes(&[0; 32]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut threefry = Threefry4x64::from_seed(1);
+
+        threefry.next_u64();
+
+        let state = threefry.save_state();
+        let mut restored = Threefry4x64::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), threefry.next_u64());
+        }
+
+        assert_eq!(threefry.snapshot(), Some(restored.save_state()));
+        assert!(Threefry4x64::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Threefry4x64::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod romu_duo_jr;
+pub mod romu_trio;
+
+// Mark Overton's Romu generators combine a multiplication with rotations, so each output needs only a few
+// instructions which can run in parallel.  See https://www.romu-random.org/ for the reference implementations.
+
+// the multiplier shared by the Romu family
+const MULTIPLIER: u64 = 15241094284759029579;
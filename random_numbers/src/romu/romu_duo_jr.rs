@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// RomuDuoJr: 128 bits of state, and the fastest of the Romu generators.  Its capacity (the number of outputs
+/// before statistical tests start to fail) is 2^51 bytes, so prefer [`super::romu_trio::RomuTrio`] for very long
+/// runs.
+#[derive(Debug, Clone)]
+pub struct RomuDuoJr {
+    x: u64,
+    y: u64
+}
+
+impl RandomNumberInit for RomuDuoJr {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<RomuDuoJr> {
+        let mut state = [0; 2];
+
+        seeds::os_random_nonzero_u64s(&mut state)?;
+
+        Ok(RomuDuoJr { x: state[0], y: state[1] })
+    }
+
+    fn from_seed(seed: u64) -> RomuDuoJr {
+        romu_duo_jr_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<RomuDuoJr, SeedError> {
+        let mut state = [0; 2];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(RomuDuoJr { x: state[0], y: state[1] })
+    }
+}
+
+impl RandomNumberState for RomuDuoJr {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::ROMU_DUO_JR);
+        writer.write_u64s(&[self.x, self.y]);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<RomuDuoJr, StateError> {
+        let mut reader = StateReader::new(state, tags::ROMU_DUO_JR)?;
+        let mut state = [0; 2];
+
+        reader.read_nonzero_u64s(&mut state)?;
+        reader.finish()?;
+
+        Ok(RomuDuoJr { x: state[0], y: state[1] })
+    }
+}
+
+impl RandomNumber for RomuDuoJr {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.x;
+
+        self.x = self.y.wrapping_mul(super::MULTIPLIER);
+        self.y = self.y.wrapping_sub(result).rotate_left(27);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn romu_duo_jr_from_split_mix(split_mix: &mut SplitMix) -> RomuDuoJr {
+    RomuDuoJr {
+        x: split_mix.next_u64(),
+        y: split_mix.next_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut romu = RomuDuoJr::new();
+
+        romu.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let romu = RomuDuoJr::from_seed(0);
+
+        assert_eq!(romu.x, 0);
+        assert_eq!(romu.y, 16294208416658607535);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from the reference implementation, https://www.romu-random.org/code.c
+        let mut romu = RomuDuoJr { x: 1, y: 2 };
+
+        let expected: [u64; 6] = [
+            1,
+            12035444495808507542,
+            178563687714390016,
+            13542421656172534717,
+            9222735459507768234,
+            14604799755248147759,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(romu.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut romu = RomuDuoJr::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(romu.next_u64(), 1);
+        assert_eq!(romu.next_u64(), 12035444495808507542);
+        assert_eq!(RomuDuoJr::from_seed_bytes(&[0; 16]).unwrap_err(), SeedError::AllZero);
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut romu = RomuDuoJr::from_seed(1);
+
+        romu.next_u64();
+
+        let state = romu.save_state();
+        let mut restored = RomuDuoJr::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), romu.next_u64());
+        }
+
+        assert_eq!(romu.snapshot(), Some(restored.save_state()));
+        assert!(RomuDuoJr::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(RomuDuoJr::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
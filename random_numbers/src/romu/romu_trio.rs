@@ -0,0 +1,155 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// RomuTrio: 192 bits of state, with a capacity (the number of outputs before statistical tests start to fail) of
+/// 2^75 bytes.  The authors' recommended general purpose Romu generator.
+#[derive(Debug, Clone)]
+pub struct RomuTrio {
+    x: u64,
+    y: u64,
+    z: u64
+}
+
+impl RandomNumberInit for RomuTrio {
+    const SEED_BYTES: usize = 24;
+
+    fn try_new() -> io::Result<RomuTrio> {
+        let mut state = [0; 3];
+
+        seeds::os_random_nonzero_u64s(&mut state)?;
+
+        Ok(RomuTrio { x: state[0], y: state[1], z: state[2] })
+    }
+
+    fn from_seed(seed: u64) -> RomuTrio {
+        romu_trio_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<RomuTrio, SeedError> {
+        let mut state = [0; 3];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(RomuTrio { x: state[0], y: state[1], z: state[2] })
+    }
+}
+
+impl RandomNumberState for RomuTrio {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::ROMU_TRIO);
+        writer.write_u64s(&[self.x, self.y, self.z]);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<RomuTrio, StateError> {
+        let mut reader = StateReader::new(state, tags::ROMU_TRIO)?;
+        let mut state = [0; 3];
+
+        reader.read_nonzero_u64s(&mut state)?;
+        reader.finish()?;
+
+        Ok(RomuTrio { x: state[0], y: state[1], z: state[2] })
+    }
+}
+
+impl RandomNumber for RomuTrio {
+    fn next_u64(&mut self) -> u64 {
+        let (x, y, z) = (self.x, self.y, self.z);
+
+        self.x = z.wrapping_mul(super::MULTIPLIER);
+        self.y = y.wrapping_sub(x).rotate_left(12);
+        self.z = z.wrapping_sub(y).rotate_left(44);
+
+        x
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn romu_trio_from_split_mix(split_mix: &mut SplitMix) -> RomuTrio {
+    RomuTrio {
+        x: split_mix.next_u64(),
+        y: split_mix.next_u64(),
+        z: split_mix.next_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut romu = RomuTrio::new();
+
+        romu.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let romu = RomuTrio::from_seed(0);
+
+        assert_eq!(romu.x, 0);
+        assert_eq!(romu.y, 16294208416658607535);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from the reference implementation, https://www.romu-random.org/code.c
+        let mut romu = RomuTrio { x: 1, y: 2, z: 3 };
+
+        let expected: [u64; 6] = [
+            1,
+            8829794706857985505,
+            14228190636816728064,
+            7047022733925001397,
+            11050715128277420919,
+            15593090640687002226,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(romu.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut romu = RomuTrio::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(romu.next_u64(), 1);
+        assert_eq!(romu.next_u64(), 8829794706857985505);
+        assert_eq!(RomuTrio::from_seed_bytes(&[0; 24]).unwrap_err(), SeedError::AllZero);
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut romu = RomuTrio::from_seed(1);
+
+        romu.next_u64();
+
+        let state = romu.save_state();
+        let mut restored = RomuTrio::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), romu.next_u64());
+        }
+
+        assert_eq!(romu.snapshot(), Some(restored.save_state()));
+        assert!(RomuTrio::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(RomuTrio::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
@@ -1,7 +1,138 @@
+use std::convert::TryInto;
+#[cfg(unix)]
+use std::fs::File;
+use std::io;
+#[cfg(unix)]
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::SeedError;
+
+// makes sure two time-based seeds taken in the same nanosecond still differ
+static TIME_SEED_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a seed based on the current time.  Only used as an explicit fallback when the operating
+/// system can't supply random bytes, see [`crate::RandomNumberInit::from_time`].
 pub fn current_nanos() -> u64 {
-    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos() as u64
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
+
+    nanos ^ TIME_SEED_COUNTER.fetch_add(1, Ordering::Relaxed).wrapping_mul(0x9E3779B97F4A7C15)
+}
+
+/// Fills `buffer` with random bytes from the operating system's entropy source.
+#[cfg(unix)]
+pub fn os_random_bytes(buffer: &mut [u8]) -> io::Result<()> {
+    File::open("/dev/urandom")?.read_exact(buffer)
+}
+
+/// Always fails, since there's no operating system entropy source for targets other than unix.  Use
+/// [`crate::RandomNumberInit::from_time`] or [`crate::from_time`] there instead.
+#[cfg(not(unix))]
+pub fn os_random_bytes(_buffer: &mut [u8]) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "no operating system entropy source is supported on this platform"))
+}
+
+/// Fills `buffer` with random `u32`s from the operating system's entropy source.
+pub fn os_random_u32s(buffer: &mut [u32]) -> io::Result<()> {
+    let mut bytes = vec![0; buffer.len() * 4];
+
+    os_random_bytes(&mut bytes)?;
+    read_u32s(&bytes, buffer);
+
+    Ok(())
+}
+
+/// Fills `buffer` with random `u64`s from the operating system's entropy source.
+pub fn os_random_u64s(buffer: &mut [u64]) -> io::Result<()> {
+    let mut bytes = vec![0; buffer.len() * 8];
+
+    os_random_bytes(&mut bytes)?;
+    read_u64s(&bytes, buffer);
+
+    Ok(())
+}
+
+/// Like [`os_random_u32s`], but never returns all zeros, for the 32-bit xorshift generators.
+pub fn os_random_nonzero_u32s(buffer: &mut [u32]) -> io::Result<()> {
+    loop {
+        os_random_u32s(buffer)?;
+
+        if buffer.iter().any(|n| *n != 0) {
+            return Ok(());
+        }
+    }
+}
+
+/// Like [`os_random_u64s`], but never returns all zeros.  The xorshift family of generators (and
+/// others, e.g. Romu) can't leave an all zero state.
+pub fn os_random_nonzero_u64s(buffer: &mut [u64]) -> io::Result<()> {
+    loop {
+        os_random_u64s(buffer)?;
+
+        if buffer.iter().any(|n| *n != 0) {
+            return Ok(());
+        }
+    }
+}
+
+/// Fills `buffer` with the little endian `u32`s in `bytes`, which must be exactly big enough.
+pub fn u32s_from_seed_bytes(bytes: &[u8], buffer: &mut [u32]) -> Result<(), SeedError> {
+    check_seed_length(bytes, buffer.len() * 4)?;
+    read_u32s(bytes, buffer);
+
+    Ok(())
+}
+
+/// Fills `buffer` with the little endian `u64`s in `bytes`, which must be exactly big enough.
+pub fn u64s_from_seed_bytes(bytes: &[u8], buffer: &mut [u64]) -> Result<(), SeedError> {
+    check_seed_length(bytes, buffer.len() * 8)?;
+    read_u64s(bytes, buffer);
+
+    Ok(())
+}
+
+/// Like [`u32s_from_seed_bytes`], but rejects a seed of all zeros, for the 32-bit xorshift generators.
+pub fn nonzero_u32s_from_seed_bytes(bytes: &[u8], buffer: &mut [u32]) -> Result<(), SeedError> {
+    u32s_from_seed_bytes(bytes, buffer)?;
+
+    if buffer.iter().all(|n| *n == 0) {
+        return Err(SeedError::AllZero);
+    }
+
+    Ok(())
+}
+
+/// Like [`u64s_from_seed_bytes`], but rejects a seed of all zeros.  The xorshift family of generators
+/// (and others, e.g. Romu) can't leave an all zero state.
+pub fn nonzero_u64s_from_seed_bytes(bytes: &[u8], buffer: &mut [u64]) -> Result<(), SeedError> {
+    u64s_from_seed_bytes(bytes, buffer)?;
+
+    if buffer.iter().all(|n| *n == 0) {
+        return Err(SeedError::AllZero);
+    }
+
+    Ok(())
+}
+
+fn check_seed_length(bytes: &[u8], expected: usize) -> Result<(), SeedError> {
+    if bytes.len() != expected {
+        return Err(SeedError::WrongLength { expected, actual: bytes.len() });
+    }
+
+    Ok(())
+}
+
+fn read_u32s(bytes: &[u8], buffer: &mut [u32]) {
+    for (n, chunk) in buffer.iter_mut().zip(bytes.chunks_exact(4)) {
+        *n = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+}
+
+fn read_u64s(bytes: &[u8], buffer: &mut [u64]) {
+    for (n, chunk) in buffer.iter_mut().zip(bytes.chunks_exact(8)) {
+        *n = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
 }
 
 #[cfg(test)]
@@ -13,4 +144,83 @@ mod tests {
         // make sure current_nanos() doesn't panic
         current_nanos();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn current_nanos_differs_test() {
+        assert_ne!(current_nanos(), current_nanos());
+    }
+
+    #[test]
+    fn os_random_bytes_test() {
+        let mut buffer = [0; 64];
+
+        assert!(os_random_bytes(&mut buffer).is_ok());
+        // 2^-512 chance of a false failure
+        assert!(buffer.iter().any(|b| *b != 0));
+    }
+
+    #[test]
+    fn os_random_u32s_test() {
+        let mut buffer = [0; 16];
+
+        assert!(os_random_u32s(&mut buffer).is_ok());
+        assert!(buffer.iter().any(|n| *n != 0));
+    }
+
+    #[test]
+    fn os_random_nonzero_u64s_test() {
+        let mut buffer = [0; 4];
+
+        assert!(os_random_nonzero_u64s(&mut buffer).is_ok());
+        assert!(buffer.iter().any(|n| *n != 0));
+    }
+
+    #[test]
+    fn os_random_nonzero_u32s_test() {
+        let mut buffer = [0; 4];
+
+        assert!(os_random_nonzero_u32s(&mut buffer).is_ok());
+        assert!(buffer.iter().any(|n| *n != 0));
+    }
+
+    #[test]
+    fn u64s_from_seed_bytes_test() {
+        let mut buffer = [0; 2];
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80];
+
+        assert!(u64s_from_seed_bytes(&bytes, &mut buffer).is_ok());
+        assert_eq!(buffer, [1, 0x8000000000000000]);
+    }
+
+    #[test]
+    fn u32s_from_seed_bytes_test() {
+        let mut buffer = [0; 2];
+
+        assert!(u32s_from_seed_bytes(&[1, 2, 3, 4, 5, 6, 7, 8], &mut buffer).is_ok());
+        assert_eq!(buffer, [0x04030201, 0x08070605]);
+    }
+
+    #[test]
+    fn seed_bytes_wrong_length_test() {
+        let mut buffer = [0; 2];
+
+        assert_eq!(u64s_from_seed_bytes(&[1; 15], &mut buffer), Err(SeedError::WrongLength { expected: 16, actual: 15 }));
+        assert_eq!(u64s_from_seed_bytes(&[1; 17], &mut buffer), Err(SeedError::WrongLength { expected: 16, actual: 17 }));
+    }
+
+    #[test]
+    fn nonzero_u64s_from_seed_bytes_test() {
+        let mut buffer = [0; 2];
+
+        assert_eq!(nonzero_u64s_from_seed_bytes(&[0; 16], &mut buffer), Err(SeedError::AllZero));
+        assert!(nonzero_u64s_from_seed_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], &mut buffer).is_ok());
+    }
+
+    #[test]
+    fn nonzero_u32s_from_seed_bytes_test() {
+        let mut buffer = [0; 2];
+
+        assert_eq!(nonzero_u32s_from_seed_bytes(&[0; 8], &mut buffer), Err(SeedError::AllZero));
+        assert!(nonzero_u32s_from_seed_bytes(&[0, 0, 0, 0, 0, 0, 0, 1], &mut buffer).is_ok());
+    }
+}
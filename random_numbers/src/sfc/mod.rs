@@ -0,0 +1,195 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    pub const RIGHT_SHIFT: u32 = 11;
+    pub const LEFT_SHIFT: u32 = 3;
+    pub const ROTATION: u32 = 24;
+
+    // PractRand discards the first 12 outputs after seeding
+    pub const INIT_CALLS: usize = 12;
+}
+
+/// Chris Doty-Humphrey's SFC64 ("small fast chaotic") from [PractRand](https://pracrand.sourceforge.net/): three
+/// words of chaotic state plus a 64-bit counter, which guarantees a period of at least 2^64.  NumPy ships it as
+/// `SFC64`.
+#[derive(Debug, Clone)]
+pub struct Sfc64 {
+    a: u64,
+    b: u64,
+    c: u64,
+    counter: u64
+}
+
+impl RandomNumberInit for Sfc64 {
+    const SEED_BYTES: usize = 32;
+
+    fn try_new() -> io::Result<Sfc64> {
+        let mut seed = [0; 3];
+
+        seeds::os_random_u64s(&mut seed)?;
+
+        Ok(sfc64_from_seeds(seed))
+    }
+
+    fn from_seed(seed: u64) -> Sfc64 {
+        sfc64_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets `a`, `b`, `c` and the counter directly, without the warm up calls [`RandomNumberInit::from_seed`]
+    /// makes.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Sfc64, SeedError> {
+        let mut state = [0; 4];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(Sfc64 {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            counter: state[3],
+        })
+    }
+}
+
+impl RandomNumberState for Sfc64 {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::SFC64);
+        writer.write_u64s(&[self.a, self.b, self.c, self.counter]);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Sfc64, StateError> {
+        let mut reader = StateReader::new(state, tags::SFC64)?;
+        let mut state = [0; 4];
+
+        reader.read_u64s(&mut state)?;
+        reader.finish()?;
+
+        Ok(Sfc64 {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            counter: state[3],
+        })
+    }
+}
+
+impl RandomNumber for Sfc64 {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.a.wrapping_add(self.b).wrapping_add(self.counter);
+
+        self.counter = self.counter.wrapping_add(1);
+        self.a = self.b ^ (self.b >> constants::RIGHT_SHIFT);
+        self.b = self.c.wrapping_add(self.c << constants::LEFT_SHIFT);
+        self.c = self.c.rotate_left(constants::ROTATION).wrapping_add(result);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn sfc64_from_split_mix(split_mix: &mut SplitMix) -> Sfc64 {
+    sfc64_from_seeds([split_mix.next_u64(), split_mix.next_u64(), split_mix.next_u64()])
+}
+
+// matches PractRand's seed(s1, s2, s3), and NumPy's seeding once it has its three words
+fn sfc64_from_seeds(seed: [u64; 3]) -> Sfc64 {
+    let mut sfc = Sfc64 {
+        a: seed[0],
+        b: seed[1],
+        c: seed[2],
+        counter: 1,
+    };
+
+    for _ in 0..constants::INIT_CALLS {
+        sfc.next_u64();
+    }
+
+    sfc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut sfc = Sfc64::new();
+
+        sfc.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let sfc = Sfc64::from_seed(1);
+        let mut split_mix = SplitMix::from_seed(1);
+        let mut expected = Sfc64 {
+            a: split_mix.next_u64(),
+            b: split_mix.next_u64(),
+            c: split_mix.next_u64(),
+            counter: 1,
+        };
+
+        for _ in 0..constants::INIT_CALLS {
+            expected.next_u64();
+        }
+
+        assert_eq!(sfc.save_state(), expected.save_state());
+        assert_eq!(sfc.counter, 13);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from PractRand's sfc64 with a = 1, b = 2, c = 3 and the counter at 1, before the warm up calls
+        let mut sfc = Sfc64 { a: 1, b: 2, c: 3, counter: 1 };
+
+        let expected: [u64; 6] = [4, 31, 452984898, 7599825428373823, 17736643746504302, 225466548354018446];
+
+        for e in expected.iter() {
+            assert_eq!(sfc.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 1].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut sfc = Sfc64::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(sfc.next_u64(), 4);
+        assert!(Sfc64::from_seed_bytes(&[0; 32]).is_ok());
+        assert!(Sfc64::from_seed_bytes(&[0; 24]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut sfc = Sfc64::from_seed(1);
+
+        sfc.next_u64();
+
+        let state = sfc.save_state();
+        let mut restored = Sfc64::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), sfc.next_u64());
+        }
+
+        assert_eq!(sfc.snapshot(), Some(restored.save_state()));
+        assert!(Sfc64::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Sfc64::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
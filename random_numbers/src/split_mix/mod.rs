@@ -1,8 +1,19 @@
+use std::io;
+
 use crate::seeds;
 use crate::RandomNumber;
 use crate::RandomNumberInit;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberSplit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
 
 mod constants{
+    // the golden gamma, 2^64 / phi rounded to an odd number
     pub const A: u64 = 0x9E3779B97f4A7C15;
 
     pub const R: u64 = 30;
@@ -12,24 +23,87 @@ mod constants{
     pub const C: u64 = 0x94D049BB133111EB;
 
     pub const T: u64 = 31;
+
+    // MurmurHash3's finalizer, used to derive the gamma of a split generator
+    pub const GAMMA_SHIFT: u64 = 33;
+    pub const GAMMA_B: u64 = 0xFF51AFD7ED558CCD;
+    pub const GAMMA_C: u64 = 0xC4CEB9FE1A85EC53;
+
+    // gammas with fewer bit transitions than this make poor Weyl sequences
+    pub const MIN_GAMMA_TRANSITIONS: u32 = 24;
+    pub const GAMMA_FIX: u64 = 0xAAAAAAAAAAAAAAAA;
 }
 
-#[derive(Debug)]
+/// SplitMix64, from Steele, Lea and Flood's [Fast Splittable Pseudorandom Number
+/// Generators](https://doi.org/10.1145/2714064.2660195): a Weyl sequence (the state plus an odd
+/// gamma) run through a mixing function.  It's also how the other generators expand a `u64` seed.
+/// 
+/// [`RandomNumberSplit::split`] derives a new generator with its own gamma, like Java's
+/// `SplittableRandom`, so recursive fork-join work can hand each task its own generator
+/// deterministically.  `from_seed(seed)` matches Java's `new SplittableRandom(seed - 0x9E3779B97F4A7C15)`,
+/// since the output is mixed from the state before it's advanced.
+#[derive(Debug, Clone)]
 pub struct SplitMix {
-    current_state: u64
+    current_state: u64,
+    gamma: u64
 }
 
 impl RandomNumberInit for SplitMix {
-    fn new() -> SplitMix {
-        SplitMix {
-            current_state: seeds::current_nanos(),
-        }
+    /// Only the state is seeded, the gamma is always the golden gamma.
+    const SEED_BYTES: usize = 8;
+
+    fn try_new() -> io::Result<SplitMix> {
+        let mut state = [0];
+
+        seeds::os_random_u64s(&mut state)?;
+
+        Ok(SplitMix {
+            current_state: state[0],
+            gamma: constants::A,
+        })
     }
 
     fn from_seed(seed: u64) -> SplitMix {
         SplitMix {
             current_state: seed,
+            gamma: constants::A,
+        }
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<SplitMix, SeedError> {
+        let mut state = [0];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(SplitMix {
+            current_state: state[0],
+            gamma: constants::A,
+        })
+    }
+}
+
+impl RandomNumberState for SplitMix {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::SPLIT_MIX);
+        writer.write_u64(self.current_state);
+        writer.write_u64(self.gamma);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<SplitMix, StateError> {
+        let mut reader = StateReader::new(state, tags::SPLIT_MIX)?;
+        let current_state = reader.read_u64()?;
+        let gamma = reader.read_u64()?;
+
+        reader.finish()?;
+
+        // split only ever creates odd gammas
+        if gamma % 2 == 0 {
+            return Err(StateError::InvalidState);
         }
+
+        Ok(SplitMix { current_state, gamma })
     }
 }
 
@@ -37,18 +111,62 @@ impl RandomNumber for SplitMix {
     fn next_u64(&mut self) -> u64 {
         mix(self)
     }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
 }
 
-fn mix(split_mix: &mut SplitMix) -> u64 {
-    let mut n = split_mix.current_state;
+impl RandomNumberAdvance for SplitMix {
+    /// The state is a Weyl sequence, so this is a single multiplication.  The period is 2^64.
+    fn advance(&mut self, delta: u128) {
+        self.current_state = self.current_state.wrapping_add(self.gamma.wrapping_mul(delta as u64));
+    }
+}
+
+impl RandomNumberSplit for SplitMix {
+    /// Matches Java's `SplittableRandom.split()`: the new generator's state is the next output and
+    /// its gamma is derived from the state after that.
+    fn split(&mut self) -> SplitMix {
+        let seed = mix(self);
+        let gamma = mix_gamma(next_seed(self));
+
+        SplitMix {
+            // Java mixes the state after advancing it, so start one step ahead
+            current_state: seed.wrapping_add(gamma),
+            gamma,
+        }
+    }
+}
+
+fn next_seed(split_mix: &mut SplitMix) -> u64 {
+    let n = split_mix.current_state;
+
+    split_mix.current_state = n.wrapping_add(split_mix.gamma);
+
+    n
+}
 
-    split_mix.current_state = n.wrapping_add(constants::A);
+fn mix(split_mix: &mut SplitMix) -> u64 {
+    let mut n = next_seed(split_mix);
 
     n = (n ^ (n >> constants::R)).wrapping_mul(constants::B);
     n = (n ^ (n >> constants::S)).wrapping_mul(constants::C);
     n ^ (n >> constants::T)
 }
 
+fn mix_gamma(mut n: u64) -> u64 {
+    n = (n ^ (n >> constants::GAMMA_SHIFT)).wrapping_mul(constants::GAMMA_B);
+    n = (n ^ (n >> constants::GAMMA_SHIFT)).wrapping_mul(constants::GAMMA_C);
+    n = (n ^ (n >> constants::GAMMA_SHIFT)) | 1;
+
+    if (n ^ (n >> 1)).count_ones() < constants::MIN_GAMMA_TRANSITIONS {
+        n ^ constants::GAMMA_FIX
+    } else {
+        n
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +213,121 @@ mod tests {
         assert_eq!(n, 0);
         assert_eq!(split_mix.current_state, constants::A);
     }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut split_mix = SplitMix::from_seed_bytes(&1234567u64.to_le_bytes()).unwrap();
+        let mut expected = SplitMix::from_seed(1234567);
+
+        assert_eq!(split_mix.next_u64(), expected.next_u64());
+        assert!(SplitMix::from_seed_bytes(&[0; 8]).is_ok());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut split_mix = SplitMix::from_seed(1);
+
+        for _ in 0..3 {
+            split_mix.next_u64();
+        }
+
+        let state = split_mix.save_state();
+        let mut restored = SplitMix::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), split_mix.next_u64());
+        }
+
+        assert_eq!(split_mix.snapshot(), Some(restored.save_state()));
+        assert!(SplitMix::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(SplitMix::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+
+    #[test]
+    fn save_restore_split_state_test() {
+        let mut split_mix = SplitMix::from_seed(1).split();
+        let mut restored = SplitMix::restore_state(&split_mix.save_state()).unwrap();
+
+        assert_eq!(restored.gamma, split_mix.gamma);
+        assert_eq!(restored.next_u64(), split_mix.next_u64());
+
+        let mut state = split_mix.save_state();
+        let last = state.len() - 8;
+        state[last] &= !1;
+
+        assert_eq!(SplitMix::restore_state(&state).unwrap_err(), StateError::InvalidState);
+    }
+
+    // reference values from Java's `new SplittableRandom(42)`
+    #[test]
+    fn splittable_random_test() {
+        let mut split_mix = SplitMix::from_seed(42u64.wrapping_add(constants::A));
+
+        assert_eq!(split_mix.next_u64(), 13679457532755275413);
+        assert_eq!(split_mix.next_u64(), 2949826092126892291);
+        assert_eq!(split_mix.next_u64(), 5139283748462763858);
+    }
+
+    #[test]
+    fn split_test() {
+        let mut split_mix = SplitMix::from_seed(42u64.wrapping_add(constants::A));
+
+        for _ in 0..3 {
+            split_mix.next_u64();
+        }
+
+        let mut child = split_mix.split();
+
+        assert_eq!(child.next_u64(), 1471864324957780161);
+        assert_eq!(child.next_u64(), 7599767345430609437);
+        assert_eq!(child.next_u64(), 6630280680088344119);
+
+        assert_eq!(split_mix.next_u64(), 16015981125662989062);
+        assert_eq!(split_mix.next_u64(), 4028864712777624925);
+
+        let mut grandchild = child.split();
+
+        assert_eq!(grandchild.next_u64(), 1769194901898560063);
+        assert_eq!(grandchild.next_u64(), 9250057042223940333);
+    }
+
+    #[test]
+    fn mix_gamma_test() {
+        let mut split_mix = SplitMix::from_seed(0);
+
+        for _ in 0..1000 {
+            let gamma = split_mix.split().gamma;
+
+            assert_eq!(gamma % 2, 1);
+            assert!((gamma ^ (gamma >> 1)).count_ones() >= constants::MIN_GAMMA_TRANSITIONS);
+        }
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut split_mix = SplitMix::from_seed(42).split();
+        let mut expected = SplitMix::from_seed(42).split();
+
+        for delta in [0, 1, 2, 7, 1000] {
+            split_mix.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(split_mix.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn advance_wraps_test() {
+        let mut split_mix = SplitMix::from_seed(42).split();
+        let mut expected = SplitMix::from_seed(42).split();
+
+        // the period is 2^64, so going back one step is the same as advancing by the period - 1
+        split_mix.next_u64();
+        split_mix.advance(u128::MAX);
+
+        assert_eq!(split_mix.next_u64(), expected.next_u64());
+    }
 }
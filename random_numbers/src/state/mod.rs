@@ -0,0 +1,331 @@
+use std::convert::TryInto;
+
+use crate::RandomNumberAlgorithm;
+use crate::StateError;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+mod constants {
+    pub const MAGIC: [u8; 4] = *b"RNGS";
+    pub const VERSION: u8 = 1;
+
+    // magic, version and algorithm tag
+    pub const HEADER_BYTES: usize = 6;
+}
+
+/// Identifies the algorithm a saved state belongs to.  These values are part of the format, never
+/// change or reuse them.
+pub mod tags {
+    pub const CHACHA8: u8 = 1;
+    pub const CHACHA12: u8 = 2;
+    pub const CHACHA20: u8 = 3;
+    pub const MERSENNE_TWISTER: u8 = 4;
+    pub const MERSENNE_TWISTER_LEGACY: u8 = 5;
+    pub const MT19937: u8 = 6;
+    pub const MIDDLE_SQUARE_WEYL_SEQUENCE: u8 = 7;
+    pub const PCG32: u8 = 8;
+    pub const PCG64: u8 = 9;
+    pub const PCG64_DXSM: u8 = 10;
+    pub const XOROSHIRO_128P: u8 = 11;
+    pub const XOROSHIRO_128PP: u8 = 12;
+    pub const XOROSHIRO_128SS: u8 = 13;
+    pub const XORSHIFT_PLUS: u8 = 14;
+    pub const XOSHIRO_256P: u8 = 15;
+    pub const XOSHIRO_256PP: u8 = 16;
+    pub const XOSHIRO_256SS: u8 = 17;
+    pub const XOSHIRO_512SS: u8 = 18;
+    pub const SPLIT_MIX: u8 = 19;
+    pub const SQUARES32: u8 = 20;
+    pub const SQUARES64: u8 = 21;
+    pub const PHILOX4X32: u8 = 22;
+    pub const THREEFRY4X64: u8 = 23;
+    pub const WYRAND: u8 = 24;
+    pub const SFC64: u8 = 25;
+    pub const JSF64: u8 = 26;
+    pub const ROMU_DUO_JR: u8 = 27;
+    pub const ROMU_TRIO: u8 = 28;
+    pub const LEHMER64: u8 = 29;
+    pub const MCG128: u8 = 30;
+    pub const XOSHIRO_128SS: u8 = 31;
+    pub const XOSHIRO_128PP: u8 = 32;
+    pub const WELL512A: u8 = 33;
+    pub const WELL1024A: u8 = 34;
+    pub const SFMT19937: u8 = 35;
+}
+
+/// Returns the algorithm a saved state belongs to, after checking its header.
+pub fn algorithm(state: &[u8]) -> Result<RandomNumberAlgorithm, StateError> {
+    let tag = read_header(state)?;
+
+    match tag {
+        tags::CHACHA8 => Ok(RandomNumberAlgorithm::ChaCha8),
+        tags::CHACHA12 => Ok(RandomNumberAlgorithm::ChaCha12),
+        tags::CHACHA20 => Ok(RandomNumberAlgorithm::ChaCha20),
+        tags::JSF64 => Ok(RandomNumberAlgorithm::Jsf64),
+        tags::LEHMER64 => Ok(RandomNumberAlgorithm::Lehmer64),
+        tags::MCG128 => Ok(RandomNumberAlgorithm::Mcg128),
+        tags::MERSENNE_TWISTER => Ok(RandomNumberAlgorithm::MersenneTwister),
+        tags::MERSENNE_TWISTER_LEGACY => Ok(RandomNumberAlgorithm::MersenneTwisterLegacy),
+        tags::MT19937 => Ok(RandomNumberAlgorithm::MT19937),
+        tags::MIDDLE_SQUARE_WEYL_SEQUENCE => Ok(RandomNumberAlgorithm::MiddleSquareWeylSequence),
+        tags::PCG32 => Ok(RandomNumberAlgorithm::Pcg32),
+        tags::PCG64 => Ok(RandomNumberAlgorithm::Pcg64),
+        tags::PCG64_DXSM => Ok(RandomNumberAlgorithm::Pcg64Dxsm),
+        tags::PHILOX4X32 => Ok(RandomNumberAlgorithm::Philox4x32),
+        tags::ROMU_DUO_JR => Ok(RandomNumberAlgorithm::RomuDuoJr),
+        tags::ROMU_TRIO => Ok(RandomNumberAlgorithm::RomuTrio),
+        tags::SFC64 => Ok(RandomNumberAlgorithm::Sfc64),
+        tags::SFMT19937 => Ok(RandomNumberAlgorithm::Sfmt19937),
+        tags::SPLIT_MIX => Ok(RandomNumberAlgorithm::SplitMix64),
+        tags::SQUARES32 => Ok(RandomNumberAlgorithm::Squares32),
+        tags::SQUARES64 => Ok(RandomNumberAlgorithm::Squares64),
+        tags::THREEFRY4X64 => Ok(RandomNumberAlgorithm::Threefry4x64),
+        tags::WELL1024A => Ok(RandomNumberAlgorithm::Well1024a),
+        tags::WELL512A => Ok(RandomNumberAlgorithm::Well512a),
+        tags::WYRAND => Ok(RandomNumberAlgorithm::WyRand),
+        tags::XOROSHIRO_128P => Ok(RandomNumberAlgorithm::Xoroshiro128P),
+        tags::XOROSHIRO_128PP => Ok(RandomNumberAlgorithm::Xoroshiro128PP),
+        tags::XOROSHIRO_128SS => Ok(RandomNumberAlgorithm::Xoroshiro128SS),
+        tags::XORSHIFT_PLUS => Ok(RandomNumberAlgorithm::XorshiftPlus),
+        tags::XOSHIRO_128PP => Ok(RandomNumberAlgorithm::Xoshiro128PP),
+        tags::XOSHIRO_128SS => Ok(RandomNumberAlgorithm::Xoshiro128SS),
+        tags::XOSHIRO_256P => Ok(RandomNumberAlgorithm::Xoshiro256P),
+        tags::XOSHIRO_256PP => Ok(RandomNumberAlgorithm::Xoshiro256PP),
+        tags::XOSHIRO_256SS => Ok(RandomNumberAlgorithm::Xoshiro256SS),
+        tags::XOSHIRO_512SS => Ok(RandomNumberAlgorithm::Xoshiro512SS),
+        _ => Err(StateError::UnknownAlgorithm(tag)),
+    }
+}
+
+fn read_header(state: &[u8]) -> Result<u8, StateError> {
+    if state.len() < constants::HEADER_BYTES || state[0..4] != constants::MAGIC {
+        return Err(StateError::NotAState);
+    }
+
+    if state[4] != constants::VERSION {
+        return Err(StateError::UnsupportedVersion(state[4]));
+    }
+
+    Ok(state[5])
+}
+
+/// Writes a state in the current version of the format.
+pub struct StateWriter {
+    bytes: Vec<u8>
+}
+
+impl StateWriter {
+    pub fn new(tag: u8) -> StateWriter {
+        let mut bytes = constants::MAGIC.to_vec();
+        bytes.push(constants::VERSION);
+        bytes.push(tag);
+
+        StateWriter { bytes }
+    }
+
+    pub fn write_u64(&mut self, n: u64) {
+        self.bytes.extend_from_slice(&n.to_le_bytes());
+    }
+
+    pub fn write_u128(&mut self, n: u128) {
+        self.bytes.extend_from_slice(&n.to_le_bytes());
+    }
+
+    pub fn write_u32s(&mut self, ns: &[u32]) {
+        for n in ns {
+            self.bytes.extend_from_slice(&n.to_le_bytes());
+        }
+    }
+
+    pub fn write_u64s(&mut self, ns: &[u64]) {
+        for n in ns {
+            self.write_u64(*n);
+        }
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Reads a state written by [`StateWriter`], checking the header matches the expected algorithm.
+pub struct StateReader<'a> {
+    bytes: &'a [u8]
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(state: &'a [u8], tag: u8) -> Result<StateReader<'a>, StateError> {
+        let actual_tag = read_header(state)?;
+
+        if actual_tag != tag {
+            return Err(StateError::WrongAlgorithm(actual_tag));
+        }
+
+        Ok(StateReader {
+            bytes: &state[constants::HEADER_BYTES..]
+        })
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, StateError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    pub fn read_u128(&mut self) -> Result<u128, StateError> {
+        Ok(u128::from_le_bytes(self.take(16)?.try_into().unwrap()))
+    }
+
+    /// Reads an index into a buffer of `len` elements, where `len` itself means the buffer is used up.
+    pub fn read_index(&mut self, len: usize) -> Result<usize, StateError> {
+        let index = self.read_u64()?;
+
+        if index > len as u64 {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(index as usize)
+    }
+
+    pub fn read_u32s(&mut self, ns: &mut [u32]) -> Result<(), StateError> {
+        let bytes = self.take(ns.len() * 4)?;
+
+        for (n, chunk) in ns.iter_mut().zip(bytes.chunks_exact(4)) {
+            *n = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        Ok(())
+    }
+
+    pub fn read_u64s(&mut self, ns: &mut [u64]) -> Result<(), StateError> {
+        for n in ns.iter_mut() {
+            *n = self.read_u64()?;
+        }
+
+        Ok(())
+    }
+
+    /// Like [`StateReader::read_u64s`], but rejects all zeros.  The xorshift family of generators (and
+    /// others, e.g. Romu) can't leave an all zero state, so it can't have been saved.
+    pub fn read_nonzero_u64s(&mut self, ns: &mut [u64]) -> Result<(), StateError> {
+        self.read_u64s(ns)?;
+
+        if ns.iter().all(|n| *n == 0) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    /// Like [`StateReader::read_u32s`], but rejects all zeros, for the 32-bit xorshift generators.
+    pub fn read_nonzero_u32s(&mut self, ns: &mut [u32]) -> Result<(), StateError> {
+        self.read_u32s(ns)?;
+
+        if ns.iter().all(|n| *n == 0) {
+            return Err(StateError::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    /// Makes sure the whole state was read.
+    pub fn finish(self) -> Result<(), StateError> {
+        if !self.bytes.is_empty() {
+            return Err(StateError::WrongLength);
+        }
+
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StateError> {
+        if self.bytes.len() < len {
+            return Err(StateError::WrongLength);
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let mut writer = StateWriter::new(tags::PCG32);
+        writer.write_u64(1);
+        writer.write_u128(2);
+        writer.write_u32s(&[3, 4]);
+        writer.write_u64s(&[5, 6]);
+
+        let state = writer.finish();
+
+        assert_eq!(&state[0..6], b"RNGS\x01\x08");
+        assert_eq!(state.len(), 6 + 8 + 16 + 8 + 16);
+
+        let mut reader = StateReader::new(&state, tags::PCG32).unwrap();
+        let mut u32s = [0; 2];
+        let mut u64s = [0; 2];
+
+        assert_eq!(reader.read_u64(), Ok(1));
+        assert_eq!(reader.read_u128(), Ok(2));
+        assert!(reader.read_u32s(&mut u32s).is_ok());
+        assert!(reader.read_u64s(&mut u64s).is_ok());
+        assert!(reader.finish().is_ok());
+
+        assert_eq!(u32s, [3, 4]);
+        assert_eq!(u64s, [5, 6]);
+    }
+
+    #[test]
+    fn header_test() {
+        let state = StateWriter::new(tags::XOSHIRO_256SS).finish();
+
+        assert_eq!(algorithm(&state), Ok(RandomNumberAlgorithm::Xoshiro256SS));
+        assert_eq!(algorithm(b"RNG"), Err(StateError::NotAState));
+        assert_eq!(algorithm(b"RNGX\x01\x11"), Err(StateError::NotAState));
+        assert_eq!(algorithm(b"RNGS\x02\x11"), Err(StateError::UnsupportedVersion(2)));
+        assert_eq!(algorithm(b"RNGS\x01\xff"), Err(StateError::UnknownAlgorithm(255)));
+        assert_eq!(StateReader::new(&state, tags::PCG32).err(), Some(StateError::WrongAlgorithm(tags::XOSHIRO_256SS)));
+    }
+
+    #[test]
+    fn wrong_length_test() {
+        let mut writer = StateWriter::new(tags::SPLIT_MIX);
+        writer.write_u64(1);
+
+        let state = writer.finish();
+
+        let mut reader = StateReader::new(&state[..10], tags::SPLIT_MIX).unwrap();
+        assert_eq!(reader.read_u64(), Err(StateError::WrongLength));
+
+        let reader = StateReader::new(&state, tags::SPLIT_MIX).unwrap();
+        assert_eq!(reader.finish(), Err(StateError::WrongLength));
+    }
+
+    #[test]
+    fn read_index_test() {
+        let mut writer = StateWriter::new(tags::MT19937);
+        writer.write_u64(624);
+        writer.write_u64(625);
+
+        let state = writer.finish();
+        let mut reader = StateReader::new(&state, tags::MT19937).unwrap();
+
+        assert_eq!(reader.read_index(624), Ok(624));
+        assert_eq!(reader.read_index(624), Err(StateError::InvalidState));
+    }
+
+    #[test]
+    fn read_nonzero_u64s_test() {
+        let mut writer = StateWriter::new(tags::XORSHIFT_PLUS);
+        writer.write_u64s(&[0, 0]);
+
+        let state = writer.finish();
+        let mut reader = StateReader::new(&state, tags::XORSHIFT_PLUS).unwrap();
+
+        assert_eq!(reader.read_nonzero_u64s(&mut [0; 2]), Err(StateError::InvalidState));
+    }
+}
@@ -0,0 +1,179 @@
+//! Serializes generators as their saved state (see [`crate::RandomNumberState`]), so every serde format
+//! stores the same stable, versioned bytes.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::RandomNumberState;
+use crate::chacha::{ChaCha, ChaChaRounds, ValidRounds};
+use crate::jsf::Jsf64;
+use crate::mcg::lehmer64::Lehmer64;
+use crate::mcg::mcg128::Mcg128;
+use crate::mersenne_twister::MersenneTwister;
+use crate::mersenne_twister::legacy::MersenneTwisterLegacy;
+use crate::mersenne_twister::mt19937::MT19937;
+use crate::mersenne_twister::sfmt::Sfmt19937;
+use crate::msws::MiddleSquaresWeylSequence;
+use crate::msws::squares::{Squares32, Squares64};
+use crate::pcg::pcg32::Pcg32;
+use crate::pcg::pcg64::Pcg64;
+use crate::pcg::pcg64_dxsm::Pcg64Dxsm;
+use crate::random123::philox::Philox4x32;
+use crate::random123::threefry::Threefry4x64;
+use crate::romu::romu_duo_jr::RomuDuoJr;
+use crate::romu::romu_trio::RomuTrio;
+use crate::sfc::Sfc64;
+use crate::split_mix::SplitMix;
+use crate::well::well1024a::Well1024a;
+use crate::well::well512a::Well512a;
+use crate::wyrand::WyRand;
+use crate::xorshift::xoroshiro_128p::Xoroshiro128P;
+use crate::xorshift::xoroshiro_128pp::Xoroshiro128PP;
+use crate::xorshift::xoroshiro_128ss::Xoroshiro128SS;
+use crate::xorshift::xorshift_plus::XorshiftPlus;
+use crate::xorshift::xoshiro_128pp::Xoshiro128PP;
+use crate::xorshift::xoshiro_128ss::Xoshiro128SS;
+use crate::xorshift::xoshiro_256p::Xoshiro256P;
+use crate::xorshift::xoshiro_256pp::Xoshiro256PP;
+use crate::xorshift::xoshiro_256ss::Xoshiro256SS;
+use crate::xorshift::xoshiro_512ss::Xoshiro512SS;
+
+struct StateVisitor<T> {
+    marker: PhantomData<T>
+}
+
+impl<'de, T: RandomNumberState> Visitor<'de> for StateVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a saved random number generator state")
+    }
+
+    fn visit_bytes<E: de::Error>(self, state: &[u8]) -> Result<T, E> {
+        T::restore_state(state).map_err(E::custom)
+    }
+
+    // formats without a native byte string, e.g. JSON, store the state as a sequence of numbers
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut state = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(byte) = seq.next_element()? {
+            state.push(byte);
+        }
+
+        self.visit_bytes(&state)
+    }
+}
+
+fn serialize_state<T: RandomNumberState, S: Serializer>(rand: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&rand.save_state())
+}
+
+fn deserialize_state<'de, T: RandomNumberState, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_bytes(StateVisitor { marker: PhantomData })
+}
+
+macro_rules! impl_serde {
+    ($($generator:ty),*) => {
+        $(
+            impl Serialize for $generator {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_state(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $generator {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$generator, D::Error> {
+                    deserialize_state(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(
+    Jsf64,
+    Lehmer64,
+    Mcg128,
+    MersenneTwister,
+    MersenneTwisterLegacy,
+    MT19937,
+    MiddleSquaresWeylSequence,
+    Pcg32,
+    Pcg64,
+    Pcg64Dxsm,
+    Philox4x32,
+    RomuDuoJr,
+    RomuTrio,
+    Sfc64,
+    Sfmt19937,
+    SplitMix,
+    Squares32,
+    Squares64,
+    Threefry4x64,
+    Well1024a,
+    Well512a,
+    WyRand,
+    Xoroshiro128P,
+    Xoroshiro128PP,
+    Xoroshiro128SS,
+    XorshiftPlus,
+    Xoshiro128PP,
+    Xoshiro128SS,
+    Xoshiro256P,
+    Xoshiro256PP,
+    Xoshiro256SS,
+    Xoshiro512SS
+);
+
+impl<const ROUNDS: usize> Serialize for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_state(self, serializer)
+    }
+}
+
+impl<'de, const ROUNDS: usize> Deserialize<'de> for ChaCha<ROUNDS> where ChaChaRounds<ROUNDS>: ValidRounds {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ChaCha<ROUNDS>, D::Error> {
+        deserialize_state(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomNumber;
+    use crate::RandomNumberInit;
+
+    #[test]
+    fn serde_json_test() {
+        let mut rand = Xoshiro256SS::from_seed(1);
+
+        let json = serde_json::to_string(&rand).unwrap();
+        let mut restored: Xoshiro256SS = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.next_u64(), rand.next_u64());
+    }
+
+    #[test]
+    fn serde_json_mersenne_twister_test() {
+        let mut rand = MersenneTwister::from_seed(1);
+        rand.next_u64();
+
+        let json = serde_json::to_string(&rand).unwrap();
+        let mut restored: MersenneTwister = serde_json::from_str(&json).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), rand.next_u64());
+        }
+    }
+
+    #[test]
+    fn serde_json_error_test() {
+        let error = serde_json::from_str::<Pcg32>("[82, 78, 71, 83]").unwrap_err();
+
+        assert!(error.to_string().contains("not a saved random number generator state"));
+    }
+}
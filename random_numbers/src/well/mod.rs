@@ -0,0 +1,38 @@
+pub mod well1024a;
+pub mod well512a;
+
+// Panneton, L'Ecuyer and Matsumoto's Well Equidistributed Long-period Linear generators, see
+// http://www.iro.umontreal.ca/~panneton/WELLRNG.html for the paper and the reference implementations.
+//
+// The reference code steps a circular buffer one word at a time, moving backwards through it.  Like the Mersenne
+// Twister, here a whole block of outputs is regenerated at once, so the index is back at the start of the buffer
+// after each block and the outputs are read out in order.
+
+// the transformation matrices from the paper, for a shift of `t` bits
+fn mat0_pos(t: u32, v: u32) -> u32 {
+    v ^ (v >> t)
+}
+
+fn mat0_neg(t: u32, v: u32) -> u32 {
+    v ^ (v << t)
+}
+
+fn mat3_neg(t: u32, v: u32) -> u32 {
+    v << t
+}
+
+fn mat4_neg(t: u32, b: u32, v: u32) -> u32 {
+    v ^ ((v << t) & b)
+}
+
+/// Runs `R` steps of a WELL recurrence starting from the beginning of `state_array`, putting each output in
+/// `block`.  `step` updates the state at the given position of the buffer and returns the new word.
+fn regenerate_well_block<const R: usize>(
+    state_array: &mut [u32; R],
+    block: &mut [u32; R],
+    step: fn(&mut [u32; R], usize) -> u32,
+) {
+    for (k, n) in block.iter_mut().enumerate() {
+        *n = step(state_array, (R - k) % R);
+    }
+}
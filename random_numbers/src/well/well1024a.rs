@@ -0,0 +1,253 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+use super::mat0_neg;
+use super::mat0_pos;
+
+mod constants {
+    pub const R: usize = 32; // number of words of state
+    pub const MASK: usize = R - 1;
+
+    pub const M1: usize = 3;
+    pub const M2: usize = 24;
+    pub const M3: usize = 10;
+}
+
+/// WELL1024a: 1024 bits of state and a period of 2^1024 - 1.  Produces the same 32-bit words as `WELLRNG1024a` from
+/// the authors' reference implementation, which returns them multiplied by 2^-32.
+///
+/// [`RandomNumber::next_u64`] combines two outputs, the first in the upper 32 bits.
+#[derive(Debug, Clone)]
+pub struct Well1024a {
+    index: usize,
+    state_array: [u32; constants::R],
+    block: [u32; constants::R]
+}
+
+impl Well1024a {
+    /// Sets the state directly, matching `InitWELLRNG1024a` from the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// If the state is all zeros.
+    pub fn from_state_array(state_array: [u32; 32]) -> Well1024a {
+        assert!(state_array.iter().any(|n| *n != 0), "the WELL state can't be all zeros");
+
+        Well1024a {
+            index: constants::R,
+            state_array,
+            block: [0; constants::R],
+        }
+    }
+}
+
+impl RandomNumberInit for Well1024a {
+    const SEED_BYTES: usize = constants::R * 4;
+
+    fn try_new() -> io::Result<Well1024a> {
+        let mut state_array = [0; constants::R];
+
+        seeds::os_random_nonzero_u32s(&mut state_array)?;
+
+        Ok(Well1024a::from_state_array(state_array))
+    }
+
+    fn from_seed(seed: u64) -> Well1024a {
+        well1024a_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets the state directly, like [`Well1024a::from_state_array`].
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Well1024a, SeedError> {
+        let mut state_array = [0; constants::R];
+
+        seeds::nonzero_u32s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Well1024a::from_state_array(state_array))
+    }
+}
+
+impl RandomNumberState for Well1024a {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::WELL1024A);
+        writer.write_u64(self.index as u64);
+        writer.write_u32s(&self.state_array);
+        writer.write_u32s(&self.block);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Well1024a, StateError> {
+        let mut reader = StateReader::new(state, tags::WELL1024A)?;
+        let index = reader.read_index(constants::R)?;
+        let mut state_array = [0; constants::R];
+        let mut block = [0; constants::R];
+
+        reader.read_nonzero_u32s(&mut state_array)?;
+        reader.read_u32s(&mut block)?;
+        reader.finish()?;
+
+        Ok(Well1024a {
+            index,
+            state_array,
+            block,
+        })
+    }
+}
+
+impl RandomNumber for Well1024a {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::R {
+            super::regenerate_well_block(&mut self.state_array, &mut self.block, step);
+            self.index = 0;
+        }
+
+        let n = self.block[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn step(state_array: &mut [u32; constants::R], i: usize) -> u32 {
+    let z0 = state_array[(i + constants::R - 1) & constants::MASK];
+    let z1 = state_array[i] ^ mat0_pos(8, state_array[(i + constants::M1) & constants::MASK]);
+    let z2 = mat0_neg(19, state_array[(i + constants::M2) & constants::MASK])
+        ^ mat0_neg(14, state_array[(i + constants::M3) & constants::MASK]);
+
+    state_array[i] = z1 ^ z2;
+
+    let n = mat0_neg(11, z0) ^ mat0_neg(7, z1) ^ mat0_neg(13, z2);
+    state_array[(i + constants::R - 1) & constants::MASK] = n;
+
+    n
+}
+
+fn well1024a_from_split_mix(split_mix: &mut SplitMix) -> Well1024a {
+    let mut state_array = [0; constants::R];
+
+    for pair in state_array.chunks_exact_mut(2) {
+        let n = split_mix.next_u64();
+
+        pair[0] = n as u32;
+        pair[1] = (n >> 32) as u32;
+    }
+
+    Well1024a::from_state_array(state_array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_state() -> [u32; constants::R] {
+        let mut state_array = [0; constants::R];
+
+        for (i, n) in state_array.iter_mut().enumerate() {
+            *n = i as u32 + 1;
+        }
+
+        state_array
+    }
+
+    #[test]
+    fn new_test() {
+        let mut well = Well1024a::new();
+
+        well.next_u32();
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from WELL1024a.c by Panneton, L'Ecuyer and Matsumoto, initialized with 1 to 32
+        let mut well = Well1024a::from_state_array(reference_state());
+
+        let expected: [u32; 6] = [1489601207, 1825104057, 1073859899, 1704532463, 3764999621, 2266464201];
+
+        for e in expected.iter() {
+            assert_eq!(well.next_u32(), *e);
+        }
+
+        for _ in 6..1000 {
+            well.next_u32();
+        }
+
+        assert_eq!(well.next_u32(), 4279606741);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut well = Well1024a::from_state_array(reference_state());
+
+        assert_eq!(well.next_u64(), 1489601207 << 32 | 1825104057);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_state_array_rejects_zero_test() {
+        Well1024a::from_state_array([0; constants::R]);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = reference_state().iter().flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut well = Well1024a::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(well.next_u32(), 1489601207);
+        assert_eq!(Well1024a::from_seed_bytes(&[0; Well1024a::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert!(Well1024a::from_seed_bytes(&[1; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut well = Well1024a::from_seed(1);
+
+        for _ in 0..3 {
+            well.next_u32();
+        }
+
+        let state = well.save_state();
+        let mut restored = Well1024a::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), well.next_u32());
+        }
+
+        assert_eq!(well.snapshot(), Some(restored.save_state()));
+        assert!(Well1024a::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Well1024a::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
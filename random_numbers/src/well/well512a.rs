@@ -0,0 +1,255 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+use super::mat0_neg;
+use super::mat0_pos;
+use super::mat3_neg;
+use super::mat4_neg;
+
+mod constants {
+    pub const R: usize = 16; // number of words of state
+    pub const MASK: usize = R - 1;
+
+    pub const M1: usize = 13;
+    pub const M2: usize = 9;
+
+    pub const B: u32 = 0xDA442D24;
+}
+
+/// WELL512a: 512 bits of state and a period of 2^512 - 1.  Produces the same 32-bit words as `WELLRNG512a` from
+/// the authors' reference implementation, which returns them multiplied by 2^-32.
+///
+/// [`RandomNumber::next_u64`] combines two outputs, the first in the upper 32 bits.
+#[derive(Debug, Clone)]
+pub struct Well512a {
+    index: usize,
+    state_array: [u32; constants::R],
+    block: [u32; constants::R]
+}
+
+impl Well512a {
+    /// Sets the state directly, matching `InitWELLRNG512a` from the reference implementation.
+    ///
+    /// # Panics
+    ///
+    /// If the state is all zeros.
+    pub fn from_state_array(state_array: [u32; 16]) -> Well512a {
+        assert!(state_array.iter().any(|n| *n != 0), "the WELL state can't be all zeros");
+
+        Well512a {
+            index: constants::R,
+            state_array,
+            block: [0; constants::R],
+        }
+    }
+}
+
+impl RandomNumberInit for Well512a {
+    const SEED_BYTES: usize = constants::R * 4;
+
+    fn try_new() -> io::Result<Well512a> {
+        let mut state_array = [0; constants::R];
+
+        seeds::os_random_nonzero_u32s(&mut state_array)?;
+
+        Ok(Well512a::from_state_array(state_array))
+    }
+
+    fn from_seed(seed: u64) -> Well512a {
+        well512a_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets the state directly, like [`Well512a::from_state_array`].
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Well512a, SeedError> {
+        let mut state_array = [0; constants::R];
+
+        seeds::nonzero_u32s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Well512a::from_state_array(state_array))
+    }
+}
+
+impl RandomNumberState for Well512a {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::WELL512A);
+        writer.write_u64(self.index as u64);
+        writer.write_u32s(&self.state_array);
+        writer.write_u32s(&self.block);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Well512a, StateError> {
+        let mut reader = StateReader::new(state, tags::WELL512A)?;
+        let index = reader.read_index(constants::R)?;
+        let mut state_array = [0; constants::R];
+        let mut block = [0; constants::R];
+
+        reader.read_nonzero_u32s(&mut state_array)?;
+        reader.read_u32s(&mut block)?;
+        reader.finish()?;
+
+        Ok(Well512a {
+            index,
+            state_array,
+            block,
+        })
+    }
+}
+
+impl RandomNumber for Well512a {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= constants::R {
+            super::regenerate_well_block(&mut self.state_array, &mut self.block, step);
+            self.index = 0;
+        }
+
+        let n = self.block[self.index];
+        self.index += 1;
+
+        n
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn step(state_array: &mut [u32; constants::R], i: usize) -> u32 {
+    let z0 = state_array[(i + constants::R - 1) & constants::MASK];
+    let z1 = mat0_neg(16, state_array[i]) ^ mat0_neg(15, state_array[(i + constants::M1) & constants::MASK]);
+    let z2 = mat0_pos(11, state_array[(i + constants::M2) & constants::MASK]);
+
+    state_array[i] = z1 ^ z2;
+
+    let n = mat0_neg(2, z0) ^ mat0_neg(18, z1) ^ mat3_neg(28, z2) ^ mat4_neg(5, constants::B, state_array[i]);
+    state_array[(i + constants::R - 1) & constants::MASK] = n;
+
+    n
+}
+
+fn well512a_from_split_mix(split_mix: &mut SplitMix) -> Well512a {
+    let mut state_array = [0; constants::R];
+
+    for pair in state_array.chunks_exact_mut(2) {
+        let n = split_mix.next_u64();
+
+        pair[0] = n as u32;
+        pair[1] = (n >> 32) as u32;
+    }
+
+    Well512a::from_state_array(state_array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference_state() -> [u32; constants::R] {
+        let mut state_array = [0; constants::R];
+
+        for (i, n) in state_array.iter_mut().enumerate() {
+            *n = i as u32 + 1;
+        }
+
+        state_array
+    }
+
+    #[test]
+    fn new_test() {
+        let mut well = Well512a::new();
+
+        well.next_u32();
+    }
+
+    #[test]
+    fn reference_test() {
+        // values from WELL512a.c by Panneton, L'Ecuyer and Matsumoto, initialized with 1 to 16
+        let mut well = Well512a::from_state_array(reference_state());
+
+        let expected: [u32; 6] = [2692481146, 2447117626, 752362814, 4237304894, 3767796794, 3498837026];
+
+        for e in expected.iter() {
+            assert_eq!(well.next_u32(), *e);
+        }
+
+        for _ in 6..1000 {
+            well.next_u32();
+        }
+
+        assert_eq!(well.next_u32(), 4025441653);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut well = Well512a::from_state_array(reference_state());
+
+        assert_eq!(well.next_u64(), 2692481146 << 32 | 2447117626);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_state_array_rejects_zero_test() {
+        Well512a::from_state_array([0; constants::R]);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = reference_state().iter().flat_map(|n| n.to_le_bytes().to_vec()).collect();
+        let mut well = Well512a::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(well.next_u32(), 2692481146);
+        assert_eq!(Well512a::from_seed_bytes(&[0; Well512a::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert!(Well512a::from_seed_bytes(&[1; 8]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut well = Well512a::from_seed(1);
+
+        for _ in 0..3 {
+            well.next_u32();
+        }
+
+        let state = well.save_state();
+        let mut restored = Well512a::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), well.next_u32());
+        }
+
+        assert_eq!(well.snapshot(), Some(restored.save_state()));
+        assert!(Well512a::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Well512a::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
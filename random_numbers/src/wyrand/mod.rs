@@ -0,0 +1,178 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberAdvance;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+mod constants {
+    // from the final version (4.2) of wyhash, https://github.com/wangyi-fudan/wyhash
+    pub const INCREMENT: u64 = 0x2D358DCCAA6C78A5;
+    pub const XOR: u64 = 0x8BB84B93962EACC9;
+}
+
+/// Wang Yi's WyRand, the generator from [wyhash](https://github.com/wangyi-fudan/wyhash): a 64-bit Weyl sequence
+/// mixed with a single 128-bit multiply.  One of the fastest generators there is, with only 64 bits of state.
+/// Matches the `fastrand` crate.
+#[derive(Debug, Clone)]
+pub struct WyRand {
+    state: u64
+}
+
+impl RandomNumberInit for WyRand {
+    const SEED_BYTES: usize = 8;
+
+    fn try_new() -> io::Result<WyRand> {
+        let mut state = [0];
+
+        seeds::os_random_u64s(&mut state)?;
+
+        Ok(WyRand { state: state[0] })
+    }
+
+    fn from_seed(seed: u64) -> WyRand {
+        wyrand_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    /// Sets the state directly, any value is fine.
+    fn from_seed_bytes(bytes: &[u8]) -> Result<WyRand, SeedError> {
+        let mut state = [0];
+
+        seeds::u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(WyRand { state: state[0] })
+    }
+}
+
+impl RandomNumberState for WyRand {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::WYRAND);
+        writer.write_u64(self.state);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<WyRand, StateError> {
+        let mut reader = StateReader::new(state, tags::WYRAND)?;
+        let state = reader.read_u64()?;
+
+        reader.finish()?;
+
+        Ok(WyRand { state })
+    }
+}
+
+impl RandomNumber for WyRand {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(constants::INCREMENT);
+
+        let t = (self.state as u128) * ((self.state ^ constants::XOR) as u128);
+
+        (t as u64) ^ ((t >> 64) as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberAdvance for WyRand {
+    /// The state is a Weyl sequence, so this is a single multiplication.  The period is 2^64.
+    fn advance(&mut self, delta: u128) {
+        self.state = self.state.wrapping_add(constants::INCREMENT.wrapping_mul(delta as u64));
+    }
+}
+
+fn wyrand_from_split_mix(split_mix: &mut SplitMix) -> WyRand {
+    WyRand {
+        state: split_mix.next_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut wyrand = WyRand::new();
+
+        wyrand.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let wyrand = WyRand::from_seed(1);
+
+        assert_eq!(wyrand.state, SplitMix::from_seed(1).next_u64());
+    }
+
+    #[test]
+    fn reference_test() {
+        // matches fastrand::Rng::with_seed(42)
+        let mut wyrand = WyRand { state: 42 };
+
+        let expected: [u64; 6] = [
+            14587678697106979209,
+            9105053682160394182,
+            14839644324764355487,
+            736379965966546952,
+            9998182218769833001,
+            8408407774846613773,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(wyrand.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn advance_test() {
+        let mut wyrand = WyRand::from_seed(1);
+        let mut expected = WyRand::from_seed(1);
+
+        for delta in [0, 1, 2, 7, 1000] {
+            wyrand.advance(delta);
+
+            for _ in 0..delta {
+                expected.next_u64();
+            }
+
+            assert_eq!(wyrand.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let mut wyrand = WyRand::from_seed_bytes(&42u64.to_le_bytes()).unwrap();
+
+        assert_eq!(wyrand.next_u64(), 14587678697106979209);
+        assert!(WyRand::from_seed_bytes(&[0; 8]).is_ok());
+        assert!(WyRand::from_seed_bytes(&[0; 7]).is_err());
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut wyrand = WyRand::from_seed(1);
+
+        wyrand.next_u64();
+
+        let state = wyrand.save_state();
+        let mut restored = WyRand::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), wyrand.next_u64());
+        }
+
+        assert_eq!(wyrand.snapshot(), Some(restored.save_state()));
+        assert!(WyRand::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(WyRand::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+    }
+}
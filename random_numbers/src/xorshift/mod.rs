@@ -1,2 +1,72 @@
+pub mod xoroshiro_128p;
+pub mod xoroshiro_128pp;
+pub mod xoroshiro_128ss;
 pub mod xorshift_plus;
-pub mod xoshiro_256ss;
\ No newline at end of file
+pub mod xoshiro_128pp;
+pub mod xoshiro_128ss;
+pub mod xoshiro_256p;
+pub mod xoshiro_256pp;
+pub mod xoshiro_256ss;
+pub mod xoshiro_512ss;
+
+// The xoshiro/xoroshiro generators share their linear engines, and only differ in how they scramble the state
+// into an output.  See https://prng.di.unimi.it/ for the reference implementations.
+
+/// Advances the 128-bit xoshiro state (shared by xoshiro128++ and xoshiro128**), which has 32-bit words.
+fn update_xoshiro_128_state(state_array: &mut [u32; 4]) {
+    let t = state_array[1] << 9;
+
+    state_array[2] ^= state_array[0];
+    state_array[3] ^= state_array[1];
+    state_array[1] ^= state_array[2];
+    state_array[0] ^= state_array[3];
+
+    state_array[2] ^= t;
+    state_array[3] = state_array[3].rotate_left(11);
+}
+
+/// Advances the 256-bit xoshiro state (shared by xoshiro256+, xoshiro256++ and xoshiro256**).
+fn update_xoshiro_256_state(state_array: &mut [u64; 4]) {
+    let t = state_array[1] << 17;
+
+    state_array[2] ^= state_array[0];
+    state_array[3] ^= state_array[1];
+    state_array[1] ^= state_array[2];
+    state_array[0] ^= state_array[3];
+
+    state_array[2] ^= t;
+    state_array[3] = state_array[3].rotate_left(45);
+}
+
+/// Advances the 512-bit xoshiro state.
+fn update_xoshiro_512_state(state_array: &mut [u64; 8]) {
+    let t = state_array[1] << 11;
+
+    state_array[2] ^= state_array[0];
+    state_array[5] ^= state_array[1];
+    state_array[1] ^= state_array[2];
+    state_array[7] ^= state_array[3];
+    state_array[3] ^= state_array[4];
+    state_array[4] ^= state_array[5];
+    state_array[0] ^= state_array[6];
+    state_array[6] ^= state_array[7];
+
+    state_array[6] ^= t;
+    state_array[7] = state_array[7].rotate_left(21);
+}
+
+/// Advances the 128-bit xoroshiro state used by xoroshiro128+ and xoroshiro128**.
+fn update_xoroshiro_128_state(state_array: &mut [u64; 2]) {
+    let s1 = state_array[1] ^ state_array[0];
+
+    state_array[0] = state_array[0].rotate_left(24) ^ s1 ^ (s1 << 16);
+    state_array[1] = s1.rotate_left(37);
+}
+
+/// Advances the 128-bit xoroshiro state used by xoroshiro128++, which uses different shift/rotate constants.
+fn update_xoroshiro_128pp_state(state_array: &mut [u64; 2]) {
+    let s1 = state_array[1] ^ state_array[0];
+
+    state_array[0] = state_array[0].rotate_left(49) ^ s1 ^ (s1 << 21);
+    state_array[1] = s1.rotate_left(28);
+}
@@ -0,0 +1,178 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoroshiro128++: 128 bits of state with the `++` scrambler.
+#[derive(Debug)]
+pub struct Xoroshiro128PP {
+    state_array: [u64; 2]
+}
+
+impl RandomNumberInit for Xoroshiro128PP {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Xoroshiro128PP> {
+        let mut state_array = [0; 2];
+
+        seeds::os_random_nonzero_u64s(&mut state_array)?;
+
+        Ok(Xoroshiro128PP { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoroshiro128PP {
+        xoroshiro_128pp_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoroshiro128PP, SeedError> {
+        let mut state_array = [0; 2];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoroshiro128PP { state_array })
+    }
+}
+
+impl RandomNumberState for Xoroshiro128PP {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOROSHIRO_128PP);
+        writer.write_u64s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoroshiro128PP, StateError> {
+        let mut reader = StateReader::new(state, tags::XOROSHIRO_128PP)?;
+        let mut state_array = [0; 2];
+
+        reader.read_nonzero_u64s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoroshiro128PP { state_array })
+    }
+}
+
+impl RandomNumber for Xoroshiro128PP {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state_array[0].wrapping_add(self.state_array[1]).rotate_left(17).wrapping_add(self.state_array[0]);
+
+        super::update_xoroshiro_128pp_state(&mut self.state_array);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoroshiro_128pp_from_split_mix(split_mix: &mut SplitMix) -> Xoroshiro128PP {
+    Xoroshiro128PP {
+        state_array: [
+            split_mix.next_u64(),
+            split_mix.next_u64()
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoroshiro128PP::new();
+
+        xoshiro.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoroshiro128PP::from_seed(0);
+
+        assert_eq!(xoshiro.state_array[0], 0);
+        assert_eq!(xoshiro.state_array[1], 16294208416658607535);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut xoshiro = Xoroshiro128PP::from_seed(0);
+
+        assert_eq!(xoshiro.next_u32(), 1349711419);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoroshiro128PP::from_seed(0);
+
+        assert_eq!(xoshiro.next_u64(), 5796966406249497665);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoroshiro128plusplus.c
+        let mut xoshiro = Xoroshiro128PP { state_array: [1, 2] };
+
+        let expected: [u64; 10] = [
+            393217,
+            669327710093319,
+            1732421326133921491,
+            11394790081659126983,
+            9555452776773192676,
+            3586421180005889563,
+            1691397964866707553,
+            10735626796753111697,
+            15216282715349408991,
+            14247243556711267923,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut xoroshiro = Xoroshiro128PP::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Xoroshiro128PP { state_array: [1, 2] };
+
+        for _ in 0..10 {
+            assert_eq!(xoroshiro.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(Xoroshiro128PP::from_seed_bytes(&[0; Xoroshiro128PP::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoroshiro128PP::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoroshiro128PP::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoroshiro = Xoroshiro128PP::from_seed(1);
+
+        for _ in 0..3 {
+            xoroshiro.next_u64();
+        }
+
+        let state = xoroshiro.save_state();
+        let mut restored = Xoroshiro128PP::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xoroshiro.next_u64());
+        }
+
+        assert_eq!(xoroshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoroshiro128PP::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoroshiro128PP::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+}
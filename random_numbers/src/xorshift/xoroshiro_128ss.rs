@@ -0,0 +1,178 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoroshiro128**: 128 bits of state with the `**` scrambler.
+#[derive(Debug)]
+pub struct Xoroshiro128SS {
+    state_array: [u64; 2]
+}
+
+impl RandomNumberInit for Xoroshiro128SS {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Xoroshiro128SS> {
+        let mut state_array = [0; 2];
+
+        seeds::os_random_nonzero_u64s(&mut state_array)?;
+
+        Ok(Xoroshiro128SS { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoroshiro128SS {
+        xoroshiro_128ss_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoroshiro128SS, SeedError> {
+        let mut state_array = [0; 2];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoroshiro128SS { state_array })
+    }
+}
+
+impl RandomNumberState for Xoroshiro128SS {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOROSHIRO_128SS);
+        writer.write_u64s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoroshiro128SS, StateError> {
+        let mut reader = StateReader::new(state, tags::XOROSHIRO_128SS)?;
+        let mut state_array = [0; 2];
+
+        reader.read_nonzero_u64s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoroshiro128SS { state_array })
+    }
+}
+
+impl RandomNumber for Xoroshiro128SS {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state_array[0].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        super::update_xoroshiro_128_state(&mut self.state_array);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoroshiro_128ss_from_split_mix(split_mix: &mut SplitMix) -> Xoroshiro128SS {
+    Xoroshiro128SS {
+        state_array: [
+            split_mix.next_u64(),
+            split_mix.next_u64()
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoroshiro128SS::new();
+
+        xoshiro.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoroshiro128SS::from_seed(0);
+
+        assert_eq!(xoshiro.state_array[0], 0);
+        assert_eq!(xoshiro.state_array[1], 16294208416658607535);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut xoshiro = Xoroshiro128SS::from_seed(0);
+
+        assert_eq!(xoshiro.next_u32(), 0);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoroshiro128SS::from_seed(0);
+
+        assert_eq!(xoshiro.next_u64(), 0);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoroshiro128starstar.c
+        let mut xoshiro = Xoroshiro128SS { state_array: [1, 2] };
+
+        let expected: [u64; 10] = [
+            5760,
+            97769243520,
+            9706862127477703552,
+            9223447511460779954,
+            8358291023205304566,
+            15695619998649302768,
+            8517900938696309774,
+            16586480348202605369,
+            6959129367028440372,
+            16822147227405758281,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut xoroshiro = Xoroshiro128SS::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Xoroshiro128SS { state_array: [1, 2] };
+
+        for _ in 0..10 {
+            assert_eq!(xoroshiro.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(Xoroshiro128SS::from_seed_bytes(&[0; Xoroshiro128SS::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoroshiro128SS::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoroshiro128SS::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoroshiro = Xoroshiro128SS::from_seed(1);
+
+        for _ in 0..3 {
+            xoroshiro.next_u64();
+        }
+
+        let state = xoroshiro.save_state();
+        let mut restored = Xoroshiro128SS::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xoroshiro.next_u64());
+        }
+
+        assert_eq!(xoroshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoroshiro128SS::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoroshiro128SS::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+}
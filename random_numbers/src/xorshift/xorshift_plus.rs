@@ -1,21 +1,81 @@
+use std::io;
+
+use crate::seeds;
 use crate::RandomNumber;
 use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::RandomNumberJump;
 use crate::split_mix::SplitMix;
 
-#[derive(Debug)]
+mod constants {
+    // The jump polynomials published alongside the reference xorshift128+ are for the (23, 18, 5) shift triple.
+    // This implementation uses (23, 17, 26), so these were computed as x^(2^64) and x^(2^96) modulo its
+    // characteristic polynomial instead.
+    pub const JUMP: [u64; 2] = [0x8C405782BCA686AD, 0xC44F35946FEF49C6];
+    pub const LONG_JUMP: [u64; 2] = [0xEEC5431970B882BC, 0x397ADBE826B37B9E];
+}
+
+#[derive(Debug, Clone)]
 pub struct XorshiftPlus {
     a_state: u64,
     b_state: u64
 }
 
 impl RandomNumberInit for XorshiftPlus {
-    fn new() -> XorshiftPlus {
-        xorshift_plus_from_split_mix(&mut SplitMix::new())
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<XorshiftPlus> {
+        let mut state = [0; 2];
+
+        seeds::os_random_nonzero_u64s(&mut state)?;
+
+        Ok(XorshiftPlus {
+            a_state: state[0],
+            b_state: state[1],
+        })
     }
 
     fn from_seed(seed: u64) -> XorshiftPlus {
         xorshift_plus_from_split_mix(&mut SplitMix::from_seed(seed))
     }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<XorshiftPlus, SeedError> {
+        let mut state = [0; 2];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state)?;
+
+        Ok(XorshiftPlus {
+            a_state: state[0],
+            b_state: state[1],
+        })
+    }
+}
+
+impl RandomNumberState for XorshiftPlus {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XORSHIFT_PLUS);
+        writer.write_u64s(&[self.a_state, self.b_state]);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<XorshiftPlus, StateError> {
+        let mut reader = StateReader::new(state, tags::XORSHIFT_PLUS)?;
+        let mut states = [0; 2];
+
+        reader.read_nonzero_u64s(&mut states)?;
+        reader.finish()?;
+
+        Ok(XorshiftPlus {
+            a_state: states[0],
+            b_state: states[1],
+        })
+    }
 }
 
 impl RandomNumber for XorshiftPlus {
@@ -32,6 +92,20 @@ impl RandomNumber for XorshiftPlus {
 
         t.wrapping_add(s)
     }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberJump for XorshiftPlus {
+    fn jump(&mut self) {
+        jump_state(self, &constants::JUMP);
+    }
+
+    fn long_jump(&mut self) {
+        jump_state(self, &constants::LONG_JUMP);
+    }
 }
 
 fn xorshift_plus_from_split_mix(split_mix: &mut SplitMix) -> XorshiftPlus {
@@ -41,6 +115,25 @@ fn xorshift_plus_from_split_mix(split_mix: &mut SplitMix) -> XorshiftPlus {
     }
 }
 
+fn jump_state(xorshift: &mut XorshiftPlus, jump_polynomial: &[u64; 2]) {
+    let mut a_jumped = 0;
+    let mut b_jumped = 0;
+
+    for j in jump_polynomial.iter() {
+        for b in 0..64 {
+            if j & (1 << b) != 0 {
+                a_jumped ^= xorshift.a_state;
+                b_jumped ^= xorshift.b_state;
+            }
+
+            xorshift.next_u64();
+        }
+    }
+
+    xorshift.a_state = a_jumped;
+    xorshift.b_state = b_jumped;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +166,75 @@ mod tests {
 
         assert_eq!(xorshift.next_u64(), 14141672521104462240);
     }
+
+    #[test]
+    fn jump_test() {
+        let mut xorshift = XorshiftPlus::from_seed(0);
+
+        xorshift.jump();
+
+        assert_eq!(xorshift.a_state, 9071675133247073590);
+        assert_eq!(xorshift.b_state, 375696819727208728);
+    }
+
+    #[test]
+    fn long_jump_test() {
+        let mut xorshift = XorshiftPlus::from_seed(0);
+
+        xorshift.long_jump();
+
+        assert_eq!(xorshift.a_state, 9553174340172695644);
+        assert_eq!(xorshift.b_state, 15943312137197913948);
+    }
+
+    #[test]
+    fn jump_state_matches_stepping_test() {
+        // x^1000 mod the characteristic polynomial, i.e. the jump polynomial for 1000 steps
+        let jump_1000: [u64; 2] = [0x1E2A16F2B481D6ED, 0xEE7FD6B91AE7F019];
+
+        let mut jumped = XorshiftPlus::from_seed(0);
+        let mut stepped = XorshiftPlus::from_seed(0);
+
+        jump_state(&mut jumped, &jump_1000);
+        for _ in 0..1000 {
+            stepped.next_u64();
+        }
+
+        assert_eq!(jumped.a_state, stepped.a_state);
+        assert_eq!(jumped.b_state, stepped.b_state);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let xorshift = XorshiftPlus::from_seed_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+
+        assert_eq!(xorshift.a_state, 1);
+        assert_eq!(xorshift.b_state, 2);
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(XorshiftPlus::from_seed_bytes(&[0; 16]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(XorshiftPlus::from_seed_bytes(&[1; 8]).unwrap_err(), SeedError::WrongLength { expected: 16, actual: 8 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xorshift = XorshiftPlus::from_seed(1);
+
+        for _ in 0..3 {
+            xorshift.next_u64();
+        }
+
+        let state = xorshift.save_state();
+        let mut restored = XorshiftPlus::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xorshift.next_u64());
+        }
+
+        assert_eq!(xorshift.snapshot(), Some(restored.save_state()));
+        assert!(XorshiftPlus::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(XorshiftPlus::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
 }
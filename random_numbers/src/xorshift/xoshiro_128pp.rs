@@ -0,0 +1,192 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoshiro128++: 128 bits of state in 32-bit words, with the `++` scrambler.  Like [`crate::Xoshiro128SS`] it
+/// produces 32 bits per call.
+#[derive(Debug, Clone)]
+pub struct Xoshiro128PP {
+    state_array: [u32; 4]
+}
+
+impl RandomNumberInit for Xoshiro128PP {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Xoshiro128PP> {
+        let mut state_array = [0; 4];
+
+        seeds::os_random_nonzero_u32s(&mut state_array)?;
+
+        Ok(Xoshiro128PP { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoshiro128PP {
+        xoshiro_128pp_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoshiro128PP, SeedError> {
+        let mut state_array = [0; 4];
+
+        seeds::nonzero_u32s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoshiro128PP { state_array })
+    }
+}
+
+impl RandomNumberState for Xoshiro128PP {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOSHIRO_128PP);
+        writer.write_u32s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoshiro128PP, StateError> {
+        let mut reader = StateReader::new(state, tags::XOSHIRO_128PP)?;
+        let mut state_array = [0; 4];
+
+        reader.read_nonzero_u32s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoshiro128PP { state_array })
+    }
+}
+
+impl RandomNumber for Xoshiro128PP {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let result = self.state_array[0].wrapping_add(self.state_array[3]).rotate_left(7).wrapping_add(self.state_array[0]);
+
+        super::update_xoshiro_128_state(&mut self.state_array);
+
+        result
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoshiro_128pp_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro128PP {
+    let n0 = split_mix.next_u64();
+    let n1 = split_mix.next_u64();
+
+    Xoshiro128PP {
+        state_array: [n0 as u32, (n0 >> 32) as u32, n1 as u32, (n1 >> 32) as u32],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoshiro128PP::new();
+
+        xoshiro.next_u32();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoshiro128PP::from_seed(1);
+        let mut split_mix = SplitMix::from_seed(1);
+        let n0 = split_mix.next_u64();
+        let n1 = split_mix.next_u64();
+
+        assert_eq!(xoshiro.state_array, [n0 as u32, (n0 >> 32) as u32, n1 as u32, (n1 >> 32) as u32]);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoshiro128plusplus.c
+        let mut xoshiro = Xoshiro128PP { state_array: [1, 2, 3, 4] };
+
+        let expected: [u32; 10] = [
+            641,
+            1573767,
+            3222811527,
+            3517856514,
+            836907274,
+            4247214768,
+            3867114732,
+            1355841295,
+            495546011,
+            621204420,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoshiro128PP { state_array: [1, 2, 3, 4] };
+
+        assert_eq!(xoshiro.next_u64(), 641 << 32 | 1573767);
+        assert_eq!(xoshiro.next_u64(), 3222811527 << 32 | 3517856514);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 4].iter().flat_map(|n: &u32| n.to_le_bytes().to_vec()).collect();
+        let mut xoshiro = Xoshiro128PP::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(xoshiro.next_u32(), 641);
+        assert_eq!(Xoshiro128PP::from_seed_bytes(&[0; Xoshiro128PP::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoshiro128PP::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoshiro128PP::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoshiro = Xoshiro128PP::from_seed(1);
+
+        for _ in 0..3 {
+            xoshiro.next_u32();
+        }
+
+        let state = xoshiro.save_state();
+        let mut restored = Xoshiro128PP::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), xoshiro.next_u32());
+        }
+
+        assert_eq!(xoshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoshiro128PP::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoshiro128PP::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+
+        let zero = Xoshiro128PP { state_array: [0; 4] }.save_state();
+
+        assert_eq!(Xoshiro128PP::restore_state(&zero).unwrap_err(), StateError::InvalidState);
+    }
+}
@@ -0,0 +1,193 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoshiro128**: 128 bits of state in 32-bit words, with the `**` scrambler.  Produces a random 32-bit number
+/// natively, so it's a good choice where 32-bit arithmetic is faster or `u32`s and `f32`s are all you need.
+/// [`RandomNumber::next_u64`] combines two outputs.
+#[derive(Debug, Clone)]
+pub struct Xoshiro128SS {
+    state_array: [u32; 4]
+}
+
+impl RandomNumberInit for Xoshiro128SS {
+    const SEED_BYTES: usize = 16;
+
+    fn try_new() -> io::Result<Xoshiro128SS> {
+        let mut state_array = [0; 4];
+
+        seeds::os_random_nonzero_u32s(&mut state_array)?;
+
+        Ok(Xoshiro128SS { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoshiro128SS {
+        xoshiro_128ss_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoshiro128SS, SeedError> {
+        let mut state_array = [0; 4];
+
+        seeds::nonzero_u32s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoshiro128SS { state_array })
+    }
+}
+
+impl RandomNumberState for Xoshiro128SS {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOSHIRO_128SS);
+        writer.write_u32s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoshiro128SS, StateError> {
+        let mut reader = StateReader::new(state, tags::XOSHIRO_128SS)?;
+        let mut state_array = [0; 4];
+
+        reader.read_nonzero_u32s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoshiro128SS { state_array })
+    }
+}
+
+impl RandomNumber for Xoshiro128SS {
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >= crate::TWO_31
+    }
+
+    #[inline]
+    fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let result = self.state_array[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        super::update_xoshiro_128_state(&mut self.state_array);
+
+        result
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) << 32 | (self.next_u32() as u64)
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoshiro_128ss_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro128SS {
+    let n0 = split_mix.next_u64();
+    let n1 = split_mix.next_u64();
+
+    Xoshiro128SS {
+        state_array: [n0 as u32, (n0 >> 32) as u32, n1 as u32, (n1 >> 32) as u32],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoshiro128SS::new();
+
+        xoshiro.next_u32();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoshiro128SS::from_seed(1);
+        let mut split_mix = SplitMix::from_seed(1);
+        let n0 = split_mix.next_u64();
+        let n1 = split_mix.next_u64();
+
+        assert_eq!(xoshiro.state_array, [n0 as u32, (n0 >> 32) as u32, n1 as u32, (n1 >> 32) as u32]);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoshiro128starstar.c
+        let mut xoshiro = Xoshiro128SS { state_array: [1, 2, 3, 4] };
+
+        let expected: [u32; 10] = [
+            11520,
+            0,
+            5927040,
+            70819200,
+            2031721883,
+            1637235492,
+            1287239034,
+            3734860849,
+            3729100597,
+            4258142804,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u32(), *e);
+        }
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoshiro128SS { state_array: [1, 2, 3, 4] };
+
+        assert_eq!(xoshiro.next_u64(), 11520 << 32);
+        assert_eq!(xoshiro.next_u64(), 5927040 << 32 | 70819200);
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 4].iter().flat_map(|n: &u32| n.to_le_bytes().to_vec()).collect();
+        let mut xoshiro = Xoshiro128SS::from_seed_bytes(&bytes).unwrap();
+
+        assert_eq!(xoshiro.next_u32(), 11520);
+        assert_eq!(Xoshiro128SS::from_seed_bytes(&[0; Xoshiro128SS::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoshiro128SS::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoshiro128SS::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoshiro = Xoshiro128SS::from_seed(1);
+
+        for _ in 0..3 {
+            xoshiro.next_u32();
+        }
+
+        let state = xoshiro.save_state();
+        let mut restored = Xoshiro128SS::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u32(), xoshiro.next_u32());
+        }
+
+        assert_eq!(xoshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoshiro128SS::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoshiro128SS::restore_state(&crate::pcg::pcg32::Pcg32::from_seed(1).save_state()).is_err());
+
+        let zero = Xoshiro128SS { state_array: [0; 4] }.save_state();
+
+        assert_eq!(Xoshiro128SS::restore_state(&zero).unwrap_err(), StateError::InvalidState);
+    }
+}
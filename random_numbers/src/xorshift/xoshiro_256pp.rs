@@ -0,0 +1,182 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoshiro256++: 256 bits of state with the `++` scrambler.
+#[derive(Debug)]
+pub struct Xoshiro256PP {
+    state_array: [u64; 4]
+}
+
+impl RandomNumberInit for Xoshiro256PP {
+    const SEED_BYTES: usize = 32;
+
+    fn try_new() -> io::Result<Xoshiro256PP> {
+        let mut state_array = [0; 4];
+
+        seeds::os_random_nonzero_u64s(&mut state_array)?;
+
+        Ok(Xoshiro256PP { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoshiro256PP {
+        xoshiro_256pp_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoshiro256PP, SeedError> {
+        let mut state_array = [0; 4];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoshiro256PP { state_array })
+    }
+}
+
+impl RandomNumberState for Xoshiro256PP {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOSHIRO_256PP);
+        writer.write_u64s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoshiro256PP, StateError> {
+        let mut reader = StateReader::new(state, tags::XOSHIRO_256PP)?;
+        let mut state_array = [0; 4];
+
+        reader.read_nonzero_u64s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoshiro256PP { state_array })
+    }
+}
+
+impl RandomNumber for Xoshiro256PP {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state_array[0].wrapping_add(self.state_array[3]).rotate_left(23).wrapping_add(self.state_array[0]);
+
+        super::update_xoshiro_256_state(&mut self.state_array);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoshiro_256pp_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro256PP {
+    Xoshiro256PP {
+        state_array: [
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64()
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoshiro256PP::new();
+
+        xoshiro.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoshiro256PP::from_seed(0);
+
+        assert_eq!(xoshiro.state_array[0], 0);
+        assert_eq!(xoshiro.state_array[1], 16294208416658607535);
+        assert_eq!(xoshiro.state_array[2], 7960286522194355700);
+        assert_eq!(xoshiro.state_array[3], 487617019471545679);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut xoshiro = Xoshiro256PP::from_seed(0);
+
+        assert_eq!(xoshiro.next_u32(), 2353005730);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoshiro256PP::from_seed(0);
+
+        assert_eq!(xoshiro.next_u64(), 10106082660461011502);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoshiro256plusplus.c
+        let mut xoshiro = Xoshiro256PP { state_array: [1, 2, 3, 4] };
+
+        let expected: [u64; 10] = [
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+            9973669472204895162,
+            14011001112246962877,
+            12406186145184390807,
+            15849039046786891736,
+            10450023813501588000,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 4].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut xoshiro = Xoshiro256PP::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Xoshiro256PP { state_array: [1, 2, 3, 4] };
+
+        for _ in 0..10 {
+            assert_eq!(xoshiro.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(Xoshiro256PP::from_seed_bytes(&[0; Xoshiro256PP::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoshiro256PP::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoshiro256PP::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoshiro = Xoshiro256PP::from_seed(1);
+
+        for _ in 0..3 {
+            xoshiro.next_u64();
+        }
+
+        let state = xoshiro.save_state();
+        let mut restored = Xoshiro256PP::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xoshiro.next_u64());
+        }
+
+        assert_eq!(xoshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoshiro256PP::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoshiro256PP::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+}
@@ -1,38 +1,94 @@
+use std::io;
+
+use crate::seeds;
 use crate::RandomNumber;
 use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::RandomNumberJump;
 use crate::split_mix::SplitMix;
 
-#[derive(Debug)]
+mod constants {
+    // jump polynomials from the reference implementation, https://prng.di.unimi.it/xoshiro256starstar.c
+    // JUMP is equivalent to 2^128 calls to next_u64, LONG_JUMP to 2^192 calls
+    pub const JUMP: [u64; 4] = [0x180EC6D33CFD0ABA, 0xD5A61266F0C9392C, 0xA9582618E03FC9AA, 0x39ABDC4529B1661C];
+    pub const LONG_JUMP: [u64; 4] = [0x76E15D3EFEFDCBBF, 0xC5004E441C522FB3, 0x77710069854EE241, 0x39109BB02ACBE635];
+}
+
+#[derive(Debug, Clone)]
 pub struct Xoshiro256SS {
     state_array: [u64; 4]
 }
 
 impl RandomNumberInit for Xoshiro256SS {
-    fn new() -> Xoshiro256SS {
-        xoshiro_ss_from_split_mix(&mut SplitMix::new())
+    const SEED_BYTES: usize = 32;
+
+    fn try_new() -> io::Result<Xoshiro256SS> {
+        let mut state_array = [0; 4];
+
+        seeds::os_random_nonzero_u64s(&mut state_array)?;
+
+        Ok(Xoshiro256SS { state_array })
     }
 
     fn from_seed(seed: u64) -> Xoshiro256SS {
         xoshiro_ss_from_split_mix(&mut SplitMix::from_seed(seed))
     }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoshiro256SS, SeedError> {
+        let mut state_array = [0; 4];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoshiro256SS { state_array })
+    }
+}
+
+impl RandomNumberState for Xoshiro256SS {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOSHIRO_256SS);
+        writer.write_u64s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoshiro256SS, StateError> {
+        let mut reader = StateReader::new(state, tags::XOSHIRO_256SS)?;
+        let mut state_array = [0; 4];
+
+        reader.read_nonzero_u64s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoshiro256SS { state_array })
+    }
 }
 
 impl RandomNumber for Xoshiro256SS {
     fn next_u64(&mut self) -> u64 {
         let result = rol(self.state_array[1].wrapping_mul(5), 7).wrapping_mul(9);
 
-        let t = self.state_array[1] << 17;
-        
-        self.state_array[2] = self.state_array[2] ^ self.state_array[0];
-        self.state_array[3] = self.state_array[3] ^ self.state_array[1];
-        self.state_array[1] = self.state_array[1] ^ self.state_array[2];
-        self.state_array[0] = self.state_array[0] ^ self.state_array[3];
-
-        self.state_array[2] = self.state_array[2] ^ t;
-        self.state_array[3] = rol(self.state_array[3], 45);
+        super::update_xoshiro_256_state(&mut self.state_array);
 
         result
     }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+impl RandomNumberJump for Xoshiro256SS {
+    fn jump(&mut self) {
+        jump_state(self, &constants::JUMP);
+    }
+
+    fn long_jump(&mut self) {
+        jump_state(self, &constants::LONG_JUMP);
+    }
 }
 
 fn xoshiro_ss_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro256SS {
@@ -46,6 +102,24 @@ fn xoshiro_ss_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro256SS {
     }
 }
 
+fn jump_state(xoshiro: &mut Xoshiro256SS, jump_polynomial: &[u64; 4]) {
+    let mut jumped = [0; 4];
+
+    for j in jump_polynomial.iter() {
+        for b in 0..64 {
+            if j & (1 << b) != 0 {
+                for (jumped_s, s) in jumped.iter_mut().zip(xoshiro.state_array.iter()) {
+                    *jumped_s ^= s;
+                }
+            }
+
+            xoshiro.next_u64();
+        }
+    }
+
+    xoshiro.state_array = jumped;
+}
+
 fn rol(n: u64, k: u64) -> u64 {
     (n << k) | (n >> (64 - k))
 }
@@ -85,8 +159,117 @@ mod tests {
         assert_eq!(xoshiro.next_u64(), 16053376993090331485);
     }
 
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoshiro256starstar.c
+        let mut xoshiro = Xoshiro256SS { state_array: [1, 2, 3, 4] };
+
+        let expected: [u64; 10] = [
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn jump_test() {
+        let mut xoshiro = Xoshiro256SS::from_seed(0);
+
+        xoshiro.jump();
+
+        assert_eq!(xoshiro.state_array, [7676464163180523379, 5372633093896488200, 14208676632983136981, 11467332041776484696]);
+    }
+
+    #[test]
+    fn long_jump_test() {
+        let mut xoshiro = Xoshiro256SS::from_seed(0);
+
+        xoshiro.long_jump();
+
+        assert_eq!(xoshiro.state_array, [4381721006593735365, 17795268287694180733, 7291505991021499961, 12987413633487788268]);
+    }
+
+    #[test]
+    fn jump_state_matches_stepping_test() {
+        // x^1000 mod the characteristic polynomial, i.e. the jump polynomial for 1000 steps
+        let jump_1000: [u64; 4] = [0x288D1EE30C52D42A, 0xC9A2D442BC6DD488, 0x7D1D43FB70DF7580, 0xD6710C4366183917];
+
+        let mut jumped = Xoshiro256SS::from_seed(0);
+        let mut stepped = Xoshiro256SS::from_seed(0);
+
+        jump_state(&mut jumped, &jump_1000);
+        for _ in 0..1000 {
+            stepped.next_u64();
+        }
+
+        assert_eq!(jumped.state_array, stepped.state_array);
+    }
+
+    #[test]
+    fn jump_streams_test() {
+        let mut xoshiro = Xoshiro256SS::from_seed(0);
+        let mut expected = Xoshiro256SS::from_seed(0);
+
+        let streams = xoshiro.jump_streams(3);
+
+        assert_eq!(streams.len(), 3);
+        for stream in streams.iter() {
+            assert_eq!(stream.state_array, expected.state_array);
+            expected.jump();
+        }
+        assert_eq!(xoshiro.state_array, expected.state_array);
+    }
+
     #[test]
     fn rol_test_0_1() {
         assert_eq!(rol(0, 1), 0);
-    } 
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 4].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut xoshiro = Xoshiro256SS::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Xoshiro256SS { state_array: [1, 2, 3, 4] };
+
+        for _ in 0..10 {
+            assert_eq!(xoshiro.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(Xoshiro256SS::from_seed_bytes(&[0; Xoshiro256SS::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoshiro256SS::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoshiro256SS::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoshiro = Xoshiro256SS::from_seed(1);
+
+        for _ in 0..3 {
+            xoshiro.next_u64();
+        }
+
+        let state = xoshiro.save_state();
+        let mut restored = Xoshiro256SS::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xoshiro.next_u64());
+        }
+
+        assert_eq!(xoshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoshiro256SS::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoshiro256SS::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
 }
@@ -0,0 +1,190 @@
+use std::io;
+
+use crate::seeds;
+use crate::RandomNumber;
+use crate::RandomNumberInit;
+use crate::SeedError;
+use crate::RandomNumberState;
+use crate::StateError;
+use crate::state::tags;
+use crate::state::StateReader;
+use crate::state::StateWriter;
+use crate::split_mix::SplitMix;
+
+/// xoshiro512**: 512 bits of state with the `**` scrambler.  Use it when you need a period larger than 2^256.
+#[derive(Debug)]
+pub struct Xoshiro512SS {
+    state_array: [u64; 8]
+}
+
+impl RandomNumberInit for Xoshiro512SS {
+    const SEED_BYTES: usize = 64;
+
+    fn try_new() -> io::Result<Xoshiro512SS> {
+        let mut state_array = [0; 8];
+
+        seeds::os_random_nonzero_u64s(&mut state_array)?;
+
+        Ok(Xoshiro512SS { state_array })
+    }
+
+    fn from_seed(seed: u64) -> Xoshiro512SS {
+        xoshiro_512ss_from_split_mix(&mut SplitMix::from_seed(seed))
+    }
+
+    fn from_seed_bytes(bytes: &[u8]) -> Result<Xoshiro512SS, SeedError> {
+        let mut state_array = [0; 8];
+
+        seeds::nonzero_u64s_from_seed_bytes(bytes, &mut state_array)?;
+
+        Ok(Xoshiro512SS { state_array })
+    }
+}
+
+impl RandomNumberState for Xoshiro512SS {
+    fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new(tags::XOSHIRO_512SS);
+        writer.write_u64s(&self.state_array);
+
+        writer.finish()
+    }
+
+    fn restore_state(state: &[u8]) -> Result<Xoshiro512SS, StateError> {
+        let mut reader = StateReader::new(state, tags::XOSHIRO_512SS)?;
+        let mut state_array = [0; 8];
+
+        reader.read_nonzero_u64s(&mut state_array)?;
+        reader.finish()?;
+
+        Ok(Xoshiro512SS { state_array })
+    }
+}
+
+impl RandomNumber for Xoshiro512SS {
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state_array[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        super::update_xoshiro_512_state(&mut self.state_array);
+
+        result
+    }
+
+    fn snapshot(&self) -> Option<Vec<u8>> {
+        Some(self.save_state())
+    }
+}
+
+fn xoshiro_512ss_from_split_mix(split_mix: &mut SplitMix) -> Xoshiro512SS {
+    Xoshiro512SS {
+        state_array: [
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64(),
+            split_mix.next_u64()
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let mut xoshiro = Xoshiro512SS::new();
+
+        xoshiro.next_u64();
+    }
+
+    #[test]
+    fn from_seed_test() {
+        let xoshiro = Xoshiro512SS::from_seed(0);
+
+        assert_eq!(xoshiro.state_array[0], 0);
+        assert_eq!(xoshiro.state_array[1], 16294208416658607535);
+        assert_eq!(xoshiro.state_array[2], 7960286522194355700);
+        assert_eq!(xoshiro.state_array[3], 487617019471545679);
+        assert_eq!(xoshiro.state_array[4], 17909611376780542444);
+        assert_eq!(xoshiro.state_array[5], 1961750202426094747);
+        assert_eq!(xoshiro.state_array[6], 6038094601263162090);
+        assert_eq!(xoshiro.state_array[7], 3207296026000306913);
+    }
+
+    #[test]
+    fn next_u32_test() {
+        let mut xoshiro = Xoshiro512SS::from_seed(0);
+
+        assert_eq!(xoshiro.next_u32(), 3737718098);
+    }
+
+    #[test]
+    fn next_u64_test() {
+        let mut xoshiro = Xoshiro512SS::from_seed(0);
+
+        assert_eq!(xoshiro.next_u64(), 16053376993090331485);
+    }
+
+    #[test]
+    fn reference_test() {
+        // values produced by the reference implementation, https://prng.di.unimi.it/xoshiro512starstar.c
+        let mut xoshiro = Xoshiro512SS { state_array: [1, 2, 3, 4, 5, 6, 7, 8] };
+
+        let expected: [u64; 10] = [
+            11520,
+            0,
+            23040,
+            23667840,
+            144955163520,
+            303992986974289920,
+            25332796375735680,
+            296904390158016,
+            13911081092387501979,
+            15304787717237593024,
+        ];
+
+        for e in expected.iter() {
+            assert_eq!(xoshiro.next_u64(), *e);
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_test() {
+        let bytes: Vec<u8> = [1, 2, 3, 4, 5, 6, 7, 8].iter().flat_map(|n: &u64| n.to_le_bytes().to_vec()).collect();
+        let mut xoshiro = Xoshiro512SS::from_seed_bytes(&bytes).unwrap();
+        let mut expected = Xoshiro512SS { state_array: [1, 2, 3, 4, 5, 6, 7, 8] };
+
+        for _ in 0..10 {
+            assert_eq!(xoshiro.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn from_seed_bytes_rejects_zero_test() {
+        assert_eq!(Xoshiro512SS::from_seed_bytes(&[0; Xoshiro512SS::SEED_BYTES]).unwrap_err(), SeedError::AllZero);
+        assert_eq!(Xoshiro512SS::from_seed_bytes(&[1; 7]).unwrap_err(), SeedError::WrongLength { expected: Xoshiro512SS::SEED_BYTES, actual: 7 });
+    }
+
+    #[test]
+    fn save_restore_state_test() {
+        let mut xoshiro = Xoshiro512SS::from_seed(1);
+
+        for _ in 0..3 {
+            xoshiro.next_u64();
+        }
+
+        let state = xoshiro.save_state();
+        let mut restored = Xoshiro512SS::restore_state(&state).unwrap();
+
+        for _ in 0..1000 {
+            assert_eq!(restored.next_u64(), xoshiro.next_u64());
+        }
+
+        assert_eq!(xoshiro.snapshot(), Some(restored.save_state()));
+        assert!(Xoshiro512SS::restore_state(&state[..state.len() - 1]).is_err());
+        assert!(Xoshiro512SS::restore_state(&crate::split_mix::SplitMix::from_seed(1).save_state()).is_err());
+    }
+}
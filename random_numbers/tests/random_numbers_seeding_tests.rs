@@ -0,0 +1,85 @@
+use random_numbers::prelude::*;
+
+const ALGORITHMS: [RandomNumberAlgorithm; 35] = [
+    RandomNumberAlgorithm::ChaCha8,
+    RandomNumberAlgorithm::ChaCha12,
+    RandomNumberAlgorithm::ChaCha20,
+    RandomNumberAlgorithm::Jsf64,
+    RandomNumberAlgorithm::Lehmer64,
+    RandomNumberAlgorithm::Mcg128,
+    RandomNumberAlgorithm::MersenneTwister,
+    RandomNumberAlgorithm::MersenneTwisterLegacy,
+    RandomNumberAlgorithm::MT19937,
+    RandomNumberAlgorithm::MiddleSquareWeylSequence,
+    RandomNumberAlgorithm::Pcg32,
+    RandomNumberAlgorithm::Pcg64,
+    RandomNumberAlgorithm::Pcg64Dxsm,
+    RandomNumberAlgorithm::Philox4x32,
+    RandomNumberAlgorithm::RomuDuoJr,
+    RandomNumberAlgorithm::RomuTrio,
+    RandomNumberAlgorithm::Sfc64,
+    RandomNumberAlgorithm::Sfmt19937,
+    RandomNumberAlgorithm::SplitMix64,
+    RandomNumberAlgorithm::Squares32,
+    RandomNumberAlgorithm::Squares64,
+    RandomNumberAlgorithm::Threefry4x64,
+    RandomNumberAlgorithm::Well1024a,
+    RandomNumberAlgorithm::Well512a,
+    RandomNumberAlgorithm::WyRand,
+    RandomNumberAlgorithm::Xoroshiro128P,
+    RandomNumberAlgorithm::Xoroshiro128PP,
+    RandomNumberAlgorithm::Xoroshiro128SS,
+    RandomNumberAlgorithm::XorshiftPlus,
+    RandomNumberAlgorithm::Xoshiro128PP,
+    RandomNumberAlgorithm::Xoshiro128SS,
+    RandomNumberAlgorithm::Xoshiro256P,
+    RandomNumberAlgorithm::Xoshiro256PP,
+    RandomNumberAlgorithm::Xoshiro256SS,
+    RandomNumberAlgorithm::Xoshiro512SS,
+];
+
+fn first_values(rand: &mut dyn RandomNumber) -> [u64; 4] {
+    [rand.next_u64(), rand.next_u64(), rand.next_u64(), rand.next_u64()]
+}
+
+#[test]
+fn new_generators_are_independent() {
+    for algorithm in ALGORITHMS {
+        let mut first = random_numbers::new(algorithm);
+        let mut second = random_numbers::new(algorithm);
+
+        assert_ne!(first_values(&mut *first), first_values(&mut *second), "{:?}", algorithm);
+    }
+}
+
+#[test]
+fn from_time_generators_are_independent() {
+    for algorithm in ALGORITHMS {
+        let mut first = random_numbers::from_time(algorithm);
+        let mut second = random_numbers::from_time(algorithm);
+
+        assert_ne!(first_values(&mut *first), first_values(&mut *second), "{:?}", algorithm);
+    }
+}
+
+#[test]
+fn try_new_succeeds() {
+    assert!(MersenneTwister::try_new().is_ok());
+    assert!(MT19937::try_new().is_ok());
+    assert!(Xoshiro256SS::try_new().is_ok());
+    assert!(XorshiftPlus::try_new().is_ok());
+    assert!(ChaCha20::try_new().is_ok());
+}
+
+#[test]
+fn from_seed_bytes_sets_full_state() {
+    for algorithm in ALGORITHMS {
+        let bytes: Vec<u8> = (0..random_numbers::seed_bytes(algorithm)).map(|n| (n % 251) as u8 + 1).collect();
+
+        let mut first = random_numbers::from_seed_bytes(algorithm, &bytes).unwrap();
+        let mut second = random_numbers::from_seed_bytes(algorithm, &bytes).unwrap();
+
+        assert_eq!(first_values(&mut *first), first_values(&mut *second), "{:?}", algorithm);
+        assert!(random_numbers::from_seed_bytes(algorithm, &bytes[1..]).is_err(), "{:?}", algorithm);
+    }
+}
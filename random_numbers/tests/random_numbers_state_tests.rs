@@ -0,0 +1,62 @@
+use random_numbers::prelude::*;
+
+const ALGORITHMS: [RandomNumberAlgorithm; 35] = [
+    RandomNumberAlgorithm::ChaCha8,
+    RandomNumberAlgorithm::ChaCha12,
+    RandomNumberAlgorithm::ChaCha20,
+    RandomNumberAlgorithm::Jsf64,
+    RandomNumberAlgorithm::Lehmer64,
+    RandomNumberAlgorithm::Mcg128,
+    RandomNumberAlgorithm::MersenneTwister,
+    RandomNumberAlgorithm::MersenneTwisterLegacy,
+    RandomNumberAlgorithm::MT19937,
+    RandomNumberAlgorithm::MiddleSquareWeylSequence,
+    RandomNumberAlgorithm::Pcg32,
+    RandomNumberAlgorithm::Pcg64,
+    RandomNumberAlgorithm::Pcg64Dxsm,
+    RandomNumberAlgorithm::Philox4x32,
+    RandomNumberAlgorithm::RomuDuoJr,
+    RandomNumberAlgorithm::RomuTrio,
+    RandomNumberAlgorithm::Sfc64,
+    RandomNumberAlgorithm::Sfmt19937,
+    RandomNumberAlgorithm::SplitMix64,
+    RandomNumberAlgorithm::Squares32,
+    RandomNumberAlgorithm::Squares64,
+    RandomNumberAlgorithm::Threefry4x64,
+    RandomNumberAlgorithm::Well1024a,
+    RandomNumberAlgorithm::Well512a,
+    RandomNumberAlgorithm::WyRand,
+    RandomNumberAlgorithm::Xoroshiro128P,
+    RandomNumberAlgorithm::Xoroshiro128PP,
+    RandomNumberAlgorithm::Xoroshiro128SS,
+    RandomNumberAlgorithm::XorshiftPlus,
+    RandomNumberAlgorithm::Xoshiro128PP,
+    RandomNumberAlgorithm::Xoshiro128SS,
+    RandomNumberAlgorithm::Xoshiro256P,
+    RandomNumberAlgorithm::Xoshiro256PP,
+    RandomNumberAlgorithm::Xoshiro256SS,
+    RandomNumberAlgorithm::Xoshiro512SS,
+];
+
+fn first_values(rand: &mut dyn RandomNumber) -> [u64; 4] {
+    [rand.next_u64(), rand.next_u64(), rand.next_u64(), rand.next_u64()]
+}
+
+#[test]
+fn snapshot_restores_boxed_generators() {
+    for algorithm in ALGORITHMS {
+        let mut rand = random_numbers::from_seed(algorithm, 1);
+        rand.next_u32();
+
+        let state = rand.snapshot().unwrap();
+        let mut restored = random_numbers::restore_state(&state).unwrap();
+
+        assert_eq!(first_values(&mut *restored), first_values(&mut *rand), "{:?}", algorithm);
+    }
+}
+
+#[test]
+fn restore_state_rejects_garbage() {
+    assert_eq!(random_numbers::restore_state(b"not a state").unwrap_err(), StateError::NotAState);
+    assert_eq!(random_numbers::restore_state(b"RNGS\x01\xff").unwrap_err(), StateError::UnknownAlgorithm(255));
+}
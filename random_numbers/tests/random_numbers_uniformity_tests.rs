@@ -23,6 +23,27 @@ fn middle_square_weyl_sequence_is_uniform() {
     assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
 }
 
+#[test]
+fn pcg32_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Pcg32);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn pcg64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Pcg64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn pcg64_dxsm_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Pcg64Dxsm);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
 #[test]
 fn xorhift_plus_is_uniform() {
     let mut rand = random_numbers::new(RandomNumberAlgorithm::XorshiftPlus);
@@ -36,3 +57,221 @@ fn xoshiro_256ss_is_uniform() {
 
     assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
 }
+
+#[test]
+fn xoroshiro_128p_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoroshiro128P);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoroshiro_128pp_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoroshiro128PP);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoroshiro_128ss_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoroshiro128SS);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoshiro_256p_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoshiro256P);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoshiro_256pp_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoshiro256PP);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoshiro_512ss_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoshiro512SS);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn chacha8_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::ChaCha8);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn chacha12_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::ChaCha12);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn chacha20_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::ChaCha20);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn mersenne_twister_legacy_is_uniform() {
+    // the legacy version's top bits are slightly biased (about 25.3% of outputs start with 00), enough to
+    // fail the 128-bit checks now and then, so use a fixed seed to keep this test repeatable
+    let mut rand = random_numbers::from_seed(RandomNumberAlgorithm::MersenneTwisterLegacy, 0);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn mt19937_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::MT19937);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn split_mix_64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::SplitMix64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn squares32_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Squares32);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn squares64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Squares64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn philox4x32_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Philox4x32);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn threefry4x64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Threefry4x64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn jsf64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Jsf64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn romu_duo_jr_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::RomuDuoJr);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn romu_trio_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::RomuTrio);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn sfc64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Sfc64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn wyrand_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::WyRand);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn lehmer64_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Lehmer64);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn mcg128_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Mcg128);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoshiro_128pp_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoshiro128PP);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn xoshiro_128ss_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Xoshiro128SS);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn sfmt19937_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Sfmt19937);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn well512a_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Well512a);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+#[test]
+fn well1024a_is_uniform() {
+    let mut rand = random_numbers::new(RandomNumberAlgorithm::Well1024a);
+
+    assert!(uniform::is_random_number_uniform(&mut *rand, None).is_ok());
+}
+
+// the float interval, bounded and range checks run many t-tests, so they use fixed seeds on a 64-bit and a 32-bit
+// generator
+#[test]
+fn float_intervals_are_uniform() {
+    assert!(uniform::check_float_intervals_uniform(&mut Xoshiro256SS::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+    assert!(uniform::check_float_intervals_uniform(&mut Pcg32::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+}
+
+#[test]
+fn below_is_uniform() {
+    assert!(uniform::check_below_uniform(&mut Xoshiro256SS::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+    assert!(uniform::check_below_uniform(&mut Pcg32::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+}
+
+#[test]
+fn range_is_uniform() {
+    assert!(uniform::check_range_uniform(&mut Xoshiro256SS::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+    assert!(uniform::check_range_uniform(&mut Pcg32::from_seed(1), uniform::NUM_SAMPLES).is_ok());
+}
@@ -1,6 +1,6 @@
 use random_numbers::prelude::*;
 
-const NUM_SAMPLES: u32 = 100_000;
+pub const NUM_SAMPLES: u32 = 100_000;
 const Z: f64 = 3.8906; // 99.99% confidence level for a t-test
 
 pub fn is_random_number_uniform(random_number: &mut dyn RandomNumber, num_samples_opt: Option<u32>) -> Result<(), &'static str> {
@@ -342,6 +342,173 @@ fn check_f64_uniform(random_number: &mut dyn RandomNumber, num_samples: u32) ->
     }
 }
 
+// the interval variants and the full precision floats should all fill [0, 1] evenly, whatever happens at the ends
+pub fn check_float_intervals_uniform(random_number: &mut dyn RandomNumber, num_samples: u32) -> Result<(), &'static str> {
+    let nexts: [fn(&mut dyn RandomNumber) -> f64; 8] = [
+        |r| r.next_f64_open(),
+        |r| r.next_f64_closed(),
+        |r| r.next_f64_open_closed(),
+        |r| r.next_f64_full_precision(),
+        |r| r.next_f32_open() as f64,
+        |r| r.next_f32_closed() as f64,
+        |r| r.next_f32_open_closed() as f64,
+        |r| r.next_f32_full_precision() as f64,
+    ];
+
+    for next in nexts.iter() {
+        let mut bucket_counts: [u32; 10] = [0; 10];
+        for _ in 0..num_samples {
+            let r = next(random_number);
+            if !(0.0..=1.0).contains(&r) {
+                return Err("float interval check returned a value outside [0, 1]");
+            }
+            bucket_counts[((r * 10.0) as usize).min(9)] += 1;
+        }
+
+        if !buckets_pass_t_test(&bucket_counts) {
+            return Err("float interval uniformity check failed");
+        }
+    }
+
+    Ok(())
+}
+
+// bounds picked to expose modulo bias: e.g. with 3 * 2^62, `next_u64() % n` would return values in the
+// first third twice as often as the others
+pub fn check_below_uniform(random_number: &mut dyn RandomNumber, num_samples: u32) -> Result<(), &'static str> {
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[random_number.next_u32_below(3) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u32 below 3 uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_u32_below(3 << 30) >> 30) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u32 below 3 * 2^30 uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[random_number.next_u64_below(3) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u64 below 3 uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_u64_below(3 << 62) >> 62) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u64 below 3 * 2^62 uniformity check failed");
+    }
+
+    let n = (1 << 63) + 1;
+    let mut bucket_counts: [u32; 8] = [0; 8];
+    for _ in 0..num_samples {
+        let below = random_number.next_u64_below(n);
+        if below >= n {
+            return Err("u64 below 2^63 + 1 out of bounds");
+        }
+
+        bucket_counts[((below as u128) * 8 / (n as u128)) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u64 below 2^63 + 1 uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_u128_below(3 << 126) >> 126) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u128 below 3 * 2^126 uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 7] = [0; 7];
+    for _ in 0..num_samples {
+        bucket_counts[random_number.next_usize_below(7)] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        Err("usize below 7 uniformity check failed")
+    } else {
+        Ok(())
+    }
+}
+
+pub fn check_range_uniform(random_number: &mut dyn RandomNumber, num_samples: u32) -> Result<(), &'static str> {
+    let mut bucket_counts: [u32; 6] = [0; 6];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_i8_range(-3, 3) + 3) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("i8 range uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 5] = [0; 5];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_u16_range_inclusive(1000, 1004) - 1000) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("u16 inclusive range uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[(random_number.next_i32_range_inclusive(i32::MIN, (1 << 30) - 1) as i64 - i32::MIN as i64) as usize / (1 << 30)] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("i32 inclusive range uniformity check failed");
+    }
+
+    let mut negative_count = 0;
+    for _ in 0..num_samples {
+        if random_number.next_i64_range_inclusive(i64::MIN, i64::MAX) < 0 {
+            negative_count += 1;
+        }
+    }
+
+    if !passes_t_test(negative_count, 0.5, num_samples) {
+        return Err("i64 full inclusive range uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        let n = random_number.next_i128_range(-(1 << 125), 1 << 126);
+        bucket_counts[((n + (1 << 125)) >> 125) as usize] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        return Err("i128 range uniformity check failed");
+    }
+
+    let mut bucket_counts: [u32; 3] = [0; 3];
+    for _ in 0..num_samples {
+        bucket_counts[random_number.next_usize_range(7, 10) - 7] += 1;
+    }
+
+    if !buckets_pass_t_test(&bucket_counts) {
+        Err("usize range uniformity check failed")
+    } else {
+        Ok(())
+    }
+}
+
 fn buckets_pass_t_test(bucket_counts: &[u32]) -> bool {
     let expected_percent = 1.0 / bucket_counts.len() as f64;
     let num_samples = bucket_counts.iter().fold(0, |sum, count| sum + count);
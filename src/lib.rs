@@ -23,7 +23,7 @@ pub mod prelude {
 
 mod random;
 
-use random::Random;
+pub use random::{BoxedRandom, Random};
 
 pub fn new_random() -> Random {
     Random::new()
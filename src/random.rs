@@ -9,6 +9,7 @@
 //! random ASCII characters and strings, random elements of a collection, etc.
 
 use random_distributions::prelude::*;
+use random_numbers::RandomNumber;
 
 // macro to implement functions like `next_bool`
 macro_rules! make_next {
@@ -26,26 +27,60 @@ macro_rules! make_next {
 /// If you need access to a specific PRNG algorith, or a non-uniform distribution, you'll
 /// need to access the desired [`DiscreteDistribution`] or [`ContinuousDistribution`]
 /// directly from the [`random_distributions`] library.
-pub struct Random {
-    uniform: Uniform
+/// 
+/// By default the generator is boxed.  To avoid the heap allocation and let every call be
+/// inlined, e.g. in a hot loop, supply a concrete generator instead:
+/// 
+/// ```
+/// use random_numbers::prelude::*;
+/// use randomness::prelude::*;
+/// 
+/// let mut rand = Random::from_random_number(Xoshiro256SS::from_seed(0));
+/// 
+/// let n = rand.next_u64();
+/// ```
+pub struct Random<R: RandomNumber = Box<dyn RandomNumber>> {
+    uniform: Uniform<R>
 }
 
-impl Random {
-    /// Creates a new [`Random`] instance, seeded with the current time in nanos. Sufficient
-    /// for most use cases.
-    pub fn new() -> Random {
+/// A [`Random`] with the default generator, boxed.
+pub type BoxedRandom = Random<Box<dyn RandomNumber>>;
+
+impl BoxedRandom {
+    /// Creates a new [`Random`] instance, seeded with random bytes from the operating system.
+    /// Sufficient for most use cases.  Falls back to seeding with the current time on platforms
+    /// where the operating system can't supply random bytes.
+    pub fn new() -> BoxedRandom {
+        let random_number = random_numbers::try_new(random_numbers::DEFAULT_RANDOM_NUMBER_ALGORITHM)
+            .unwrap_or_else(|_| random_numbers::from_time(random_numbers::DEFAULT_RANDOM_NUMBER_ALGORITHM));
+
         Random {
-            uniform: new_uniform(None)
+            uniform: Uniform::from_random_number(random_number)
         }
     }
 
     /// Creates a new [`Random`] instance with the given seed.  Useful when you need a repeatable
     /// "random" sequence of values.
-    pub fn from_seed(seed: u64) -> Random {
+    pub fn from_seed(seed: u64) -> BoxedRandom {
         Random {
             uniform: from_seed_uniform(None, seed)
         }
     }
+}
+
+impl Default for BoxedRandom {
+    fn default() -> BoxedRandom {
+        Random::new()
+    }
+}
+
+impl<R: RandomNumber> Random<R> {
+    /// Creates a new [`Random`] instance using the given generator.
+    pub fn from_random_number(random_number: R) -> Random<R> {
+        Random {
+            uniform: Uniform::from_random_number(random_number)
+        }
+    }
     
     make_next!(next_bool, bool);
     make_next!(next_u8, u8);
@@ -65,6 +100,17 @@ impl Random {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use random_numbers::RandomNumberInit;
+    use random_numbers::Xoshiro256SS;
+
+    #[test]
+    fn from_random_number_test() {
+        let mut random = Random::from_random_number(Xoshiro256SS::from_seed(0));
+        let mut expected = Random::from_seed(0);
+
+        assert_eq!(random.next_u64(), expected.next_u64());
+        assert_eq!(random.next_f64(), expected.next_f64());
+    }
 
     #[test]
     fn next_bool_test() {